@@ -0,0 +1,100 @@
+use std::cell::Cell;
+
+use edgy::{
+    themes,
+    widgets::{
+        button::Button,
+        linear_layout::{LayoutAlignment, LayoutDirection, LinearLayoutBuilder},
+        UiBuilder, WidgetObject,
+    },
+    SystemEvent, UiContext,
+};
+use embedded_graphics::{
+    mono_font::ascii::{FONT_10X20, FONT_6X10},
+    pixelcolor::Rgb888,
+    prelude::*,
+    text::Alignment,
+};
+use embedded_graphics_simulator::{
+    OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
+};
+
+fn control_ui<'a, D>(counter: &'a Cell<i32>) -> WidgetObject<'a, D, Rgb888>
+where
+    D: DrawTarget<Color = Rgb888> + 'a,
+{
+    let mut ui = LinearLayoutBuilder::default()
+        .horizontal_alignment(LayoutAlignment::Center)
+        .vertical_alignment(LayoutAlignment::Center)
+        .direction(LayoutDirection::Horizontal);
+
+    ui.add_widget(Button::new(
+        "-".into(),
+        &FONT_10X20,
+        Box::new(|| counter.set(counter.get() - 1)),
+    ));
+    ui.add_widget(Button::new(
+        "+".into(),
+        &FONT_10X20,
+        Box::new(|| counter.set(counter.get() + 1)),
+    ));
+    ui.finish()
+}
+
+fn status_ui<'a, D>(counter: &Cell<i32>) -> WidgetObject<'a, D, Rgb888>
+where
+    D: DrawTarget<Color = Rgb888> + 'a,
+{
+    let mut ui = LinearLayoutBuilder::default()
+        .horizontal_alignment(LayoutAlignment::Center)
+        .vertical_alignment(LayoutAlignment::Center);
+
+    ui.label(
+        format!("value: {}", counter.get()),
+        Alignment::Center,
+        &FONT_6X10,
+    );
+    ui.finish()
+}
+
+fn main() -> Result<(), core::convert::Infallible> {
+    let output_settings = OutputSettingsBuilder::new().scale(2).build();
+    let mut control_window = Window::new("controls", &output_settings);
+    let mut status_window = Window::new("status", &output_settings);
+
+    // widgets borrow the counter, declare it before the contexts
+    let counter = Cell::new(0);
+
+    // theme is `Copy`, both contexts start from the same value
+    let theme = themes::hope_diamond::apply();
+    let mut control_ctx =
+        UiContext::new(SimulatorDisplay::<Rgb888>::new(Size::new(160, 80)), theme);
+    let mut status_ctx = UiContext::new(SimulatorDisplay::<Rgb888>::new(Size::new(128, 32)), theme);
+
+    loop {
+        // each context counts its own widget ids, the UIs are rebuilt every frame
+        control_window
+            .update(control_ctx.frame(Rgb888::BLACK, |ctx| ctx.update(control_ui(&counter))));
+        status_window
+            .update(status_ctx.frame(Rgb888::BLACK, |ctx| ctx.update(status_ui(&counter))));
+
+        for event in control_window.events() {
+            match event {
+                SimulatorEvent::Quit => std::process::exit(0),
+                SimulatorEvent::MouseButtonDown { point, .. } => {
+                    control_ctx.push_event(SystemEvent::Active(point));
+                }
+                SimulatorEvent::MouseMove { point } => {
+                    control_ctx.push_event(SystemEvent::Move(point));
+                }
+                _ => {}
+            }
+        }
+
+        for event in status_window.events() {
+            if matches!(event, SimulatorEvent::Quit) {
+                std::process::exit(0);
+            }
+        }
+    }
+}
@@ -39,18 +39,15 @@ fn main() -> Result<(), core::convert::Infallible> {
         .build();
 
     let mut window = Window::new("a bit edgy ui", &output_settings);
-    let mut ui_ctx = UiContext::new(display, themes::hope_diamond::apply());
-
     let bmp = Bmp::<Rgb888>::from_slice(include_bytes!("bee.bmp")).unwrap();
     println!(
         "bitmap: {} pixels: {}",
         bmp.bounding_box().size,
         bmp.pixels().count()
     );
+    let mut ui_ctx = UiContext::new(display, themes::hope_diamond::apply());
 
     loop {
-        window.update(&ui_ctx.draw_target);
-
         for event in window.events() {
             match event {
                 embedded_graphics_simulator::SimulatorEvent::Quit => {
@@ -69,7 +66,6 @@ fn main() -> Result<(), core::convert::Infallible> {
             }
         }
 
-        ui_ctx.draw_target.clear(Rgb888::BLACK)?;
-        ui_ctx.update(demo_ui(&bmp));
+        window.update(ui_ctx.frame(Rgb888::BLACK, |ctx| ctx.update(demo_ui(&bmp))));
     }
 }
@@ -234,9 +234,10 @@ fn main() -> Result<(), core::convert::Infallible> {
     let mut window = Window::new("a bit edgy ui", &output_settings);
     let debug_text_style = MonoTextStyle::new(&FONT_4X6, Rgb888::BLUE);
 
-    let mut ui_ctx = UiContext::new(display, themes::hope_diamond::apply());
+    // widgets borrow the state, declare it before the context
     let mut default_state = UiState::default();
     let state = &RefCell::new(&mut default_state);
+    let mut ui_ctx = UiContext::new(display, themes::hope_diamond::apply());
 
     ui_ctx.alert("WELCOME TO EDGY!\nThis is testbed rather than example...\nSo, go ahead and test how it works (or not :P)");
 
@@ -0,0 +1,196 @@
+//! Supersampled (anti-aliased) drawing for circular widgets. Every pixel is tested at several
+//! points and blended with the background by the covered fraction. Much slower than the aliased
+//! `embedded_graphics` primitives, widgets use it only when asked, e.g.
+//! [crate::widgets::gauge::Gauge::set_antialiased]
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Circle, Rectangle},
+};
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
+/// Samples per pixel side, each pixel is tested at `SAMPLES * SAMPLES` points
+const SAMPLES: u32 = 4;
+
+/// Blends two colors, `t` is the fraction of the second one
+pub type Blend<C> = fn(C, C, f32) -> C;
+
+/// Draws `color` over the pixels of `area` covered by the `inside` shape, partially covered
+/// pixels are blended with `background`. `inside` takes continuous coordinates
+fn fill_coverage<D, C>(
+    target: &mut D,
+    area: Rectangle,
+    color: C,
+    background: C,
+    blend: Blend<C>,
+    inside: impl Fn(f32, f32) -> bool,
+) where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    let step = 1.0 / SAMPLES as f32;
+    let pixels = area.points().filter_map(|point| {
+        let mut covered = 0;
+        for sample_y in 0..SAMPLES {
+            for sample_x in 0..SAMPLES {
+                let x = point.x as f32 + (sample_x as f32 + 0.5) * step;
+                let y = point.y as f32 + (sample_y as f32 + 0.5) * step;
+                if inside(x, y) {
+                    covered += 1;
+                }
+            }
+        }
+
+        match covered {
+            0 => None,
+            covered if covered == SAMPLES * SAMPLES => Some(Pixel(point, color)),
+            covered => Some(Pixel(
+                point,
+                blend(
+                    background,
+                    color,
+                    covered as f32 / (SAMPLES * SAMPLES) as f32,
+                ),
+            )),
+        }
+    });
+
+    let _ = target.draw_iter(pixels);
+}
+
+/// Anti-aliased counterpart of [embedded_graphics::primitives::Arc::from_circle] drawn with a
+/// stroke of `width` centered on the circle outline. Angles are in degrees, `0` points right and
+/// positive values go clockwise
+#[allow(clippy::too_many_arguments)]
+pub fn draw_arc<D, C>(
+    target: &mut D,
+    circle: Circle,
+    start: f32,
+    sweep: f32,
+    width: u32,
+    color: C,
+    background: C,
+    blend: Blend<C>,
+) where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    let radius = circle.diameter as f32 / 2.0;
+    let half_width = width as f32 / 2.0;
+    let center_x = circle.top_left.x as f32 + radius;
+    let center_y = circle.top_left.y as f32 + radius;
+    let (start, sweep) = if sweep < 0.0 {
+        (start + sweep, -sweep)
+    } else {
+        (start, sweep)
+    };
+
+    let area = circle.bounding_box().offset(width.div_ceil(2) as i32);
+    fill_coverage(target, area, color, background, blend, |x, y| {
+        let (dx, dy) = (x - center_x, y - center_y);
+        let distance = (dx * dx + dy * dy).sqrt();
+        if (distance - radius).abs() > half_width {
+            return false;
+        }
+
+        if sweep >= 360.0 {
+            return true;
+        }
+
+        let mut angle = (dy.atan2(dx).to_degrees() - start) % 360.0;
+        if angle < 0.0 {
+            angle += 360.0;
+        }
+        angle <= sweep
+    });
+}
+
+/// Anti-aliased line of `width` from `start` to `end`, in continuous coordinates
+pub fn draw_line<D, C>(
+    target: &mut D,
+    start: (f32, f32),
+    end: (f32, f32),
+    width: f32,
+    color: C,
+    background: C,
+    blend: Blend<C>,
+) where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    let half_width = width / 2.0;
+    let (line_x, line_y) = (end.0 - start.0, end.1 - start.1);
+    let length_squared = line_x * line_x + line_y * line_y;
+
+    let area = Rectangle::with_corners(
+        Point::new(
+            (start.0.min(end.0) - half_width).floor() as i32,
+            (start.1.min(end.1) - half_width).floor() as i32,
+        ),
+        Point::new(
+            (start.0.max(end.0) + half_width).ceil() as i32,
+            (start.1.max(end.1) + half_width).ceil() as i32,
+        ),
+    );
+
+    fill_coverage(target, area, color, background, blend, |x, y| {
+        // distance from the closest point of the segment
+        let t = if length_squared > 0.0 {
+            (((x - start.0) * line_x + (y - start.1) * line_y) / length_squared).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let (dx, dy) = (x - start.0 - t * line_x, y - start.1 - t * line_y);
+        dx * dx + dy * dy <= half_width * half_width
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::themes::palette::blend;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::Rgb888,
+        primitives::{Arc, PrimitiveStyle},
+    };
+
+    #[test]
+    fn arc_edge_is_blended() {
+        let circle = Circle::new(Point::new(8, 8), 40);
+
+        let mut aliased = MockDisplay::<Rgb888>::new();
+        let _ = Arc::from_circle(circle, Angle::zero(), Angle::from_degrees(90.0))
+            .into_styled(PrimitiveStyle::with_stroke(Rgb888::WHITE, 3))
+            .draw(&mut aliased);
+
+        let mut smooth = MockDisplay::<Rgb888>::new();
+        draw_arc(
+            &mut smooth,
+            circle,
+            0.0,
+            90.0,
+            3,
+            Rgb888::WHITE,
+            Rgb888::BLACK,
+            blend,
+        );
+
+        let count = |display: &MockDisplay<Rgb888>, blended: bool| {
+            display
+                .bounding_box()
+                .points()
+                .filter_map(|point| display.get_pixel(point))
+                .filter(|&color| (color != Rgb888::WHITE) == blended)
+                .count()
+        };
+
+        // aliased arc has only solid pixels
+        assert_eq!(count(&aliased, true), 0);
+        // anti-aliased arc keeps a solid core and adds blended pixels along the edges
+        assert!(count(&smooth, false) > 0);
+        assert!(count(&smooth, true) > count(&smooth, false) / 2);
+        assert!(count(&smooth, true) + count(&smooth, false) > count(&aliased, false));
+    }
+}
@@ -0,0 +1,55 @@
+//! Easing curves for animated values. Every curve takes a progress in range 0.0-1.0 (clamped)
+//! and returns the eased progress, starting at 0.0 and ending at 1.0
+
+/// Constant speed
+pub fn linear(t: f32) -> f32 {
+    t.clamp(0.0, 1.0)
+}
+
+/// Cubic ease-in-out, slow start and slow end
+pub fn ease_in_out(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        let inverse = -2.0 * t + 2.0;
+        1.0 - inverse * inverse * inverse / 2.0
+    }
+}
+
+/// Cubic ease-out, fast start and slow end. Good for needles settling on a value
+pub fn ease_out(t: f32) -> f32 {
+    let inverse = 1.0 - t.clamp(0.0, 1.0);
+    1.0 - inverse * inverse * inverse
+}
+
+/// Interpolates between `from` and `to` using an easing curve
+pub fn interpolate(from: f32, to: f32, t: f32, curve: fn(f32) -> f32) -> f32 {
+    from + (to - from) * curve(t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURVES: [fn(f32) -> f32; 3] = [linear, ease_in_out, ease_out];
+
+    #[test]
+    fn curves_keep_endpoints() {
+        for curve in CURVES {
+            assert_eq!(curve(0.0), 0.0);
+            assert_eq!(curve(1.0), 1.0);
+            assert_eq!(curve(-1.0), 0.0);
+            assert_eq!(curve(2.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn curve_midpoints() {
+        assert_eq!(linear(0.5), 0.5);
+        assert_eq!(ease_in_out(0.5), 0.5);
+        assert_eq!(ease_out(0.5), 0.875);
+        assert!(ease_in_out(0.25) < linear(0.25));
+        assert_eq!(interpolate(10.0, 20.0, 0.5, ease_out), 18.75);
+    }
+}
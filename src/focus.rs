@@ -0,0 +1,103 @@
+//! Animated focus indicator, slides a focus rectangle between widgets when focus changes
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+use crate::easing;
+
+/// Focus rectangle animation state. The context feeds it the focused widget rect every frame
+#[derive(Clone, Copy, Debug)]
+pub struct FocusIndicator {
+    /// Transition length in frames
+    pub frames: u32,
+    target_id: usize,
+    from: Rectangle,
+    to: Rectangle,
+    start_frame: u32,
+}
+
+impl FocusIndicator {
+    pub fn new(frames: u32) -> Self {
+        Self {
+            frames,
+            target_id: 0,
+            from: Rectangle::zero(),
+            to: Rectangle::zero(),
+            start_frame: 0,
+        }
+    }
+
+    /// Updates the target widget. A new target starts a transition from the currently shown
+    /// rect, so focus changes in the middle of a transition do not jump
+    pub fn retarget(&mut self, id: usize, rect: Rectangle, frame: u32) {
+        if id == self.target_id {
+            self.to = rect;
+            return;
+        }
+
+        self.from = if self.target_id == 0 {
+            rect
+        } else {
+            self.rect(frame)
+        };
+        self.to = rect;
+        self.target_id = id;
+        self.start_frame = frame;
+    }
+
+    /// Clears the target, next focused widget gets the indicator without a transition
+    pub fn reset(&mut self) {
+        self.target_id = 0;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.target_id != 0
+    }
+
+    /// Returns the indicator rect at the specified frame
+    pub fn rect(&self, frame: u32) -> Rectangle {
+        let elapsed = frame.wrapping_sub(self.start_frame);
+        if self.frames == 0 || elapsed >= self.frames {
+            return self.to;
+        }
+
+        let progress = elapsed as f32 / self.frames as f32;
+        let lerp = |from: i32, to: i32| {
+            easing::interpolate(from as f32, to as f32, progress, easing::ease_out) as i32
+        };
+
+        Rectangle::new(
+            Point::new(
+                lerp(self.from.top_left.x, self.to.top_left.x),
+                lerp(self.from.top_left.y, self.to.top_left.y),
+            ),
+            Size::new(
+                lerp(self.from.size.width as i32, self.to.size.width as i32) as u32,
+                lerp(self.from.size.height as i32, self.to.size.height as i32) as u32,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indicator_slides_between_rects() {
+        let first = Rectangle::new(Point::new(0, 0), Size::new(10, 10));
+        let second = Rectangle::new(Point::new(40, 20), Size::new(20, 10));
+
+        let mut indicator = FocusIndicator::new(4);
+        indicator.retarget(1, first, 0);
+        assert_eq!(indicator.rect(0), first);
+
+        indicator.retarget(2, second, 10);
+        assert_eq!(indicator.rect(10), first);
+
+        let middle = indicator.rect(12);
+        assert!(middle.top_left.x > first.top_left.x && middle.top_left.x < second.top_left.x);
+        assert!(middle.top_left.y > first.top_left.y && middle.top_left.y < second.top_left.y);
+        assert!(middle.size.width > first.size.width && middle.size.width < second.size.width);
+
+        assert_eq!(indicator.rect(14), second);
+    }
+}
@@ -2,33 +2,42 @@
 //! edgy - no_std immediate-mode GUI library for microcontrollers. It uses ``embedded_graphics`` for
 //! rendering and some types like ``Color`` or ``Rectangle``. Library uses ``alloc`` for widget
 //! dynamic dispatch, threfore a allocator is required.
-use alloc::{boxed::Box, rc::Rc, string::String};
-use core::{
-    cell::RefCell,
-    marker::PhantomData,
-    sync::atomic::{AtomicUsize, Ordering},
-    u32,
-};
+use alloc::{boxed::Box, rc::Rc, string::String, vec::Vec};
+use core::{cell::RefCell, marker::PhantomData, u32};
 pub use embedded_graphics;
-use themes::Theme;
+use focus::FocusIndicator;
+use overflow::{OverflowTarget, OverflowTracker};
+use rotation::{Rotation, RotatedTarget};
+use themes::{ColorRole, DynamicStyle, Theme, WidgetStyle};
 
-use embedded_graphics::{prelude::*, primitives::Rectangle};
+use embedded_graphics::{
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+};
+use text::TextMeasure;
 use widgets::{
-    alert::Alert, root_layout::{Anchor, RootLayout}, WidgetObject
+    alert::Alert, root_layout::{Anchor, RootLayout}, Modifier, WidgetObject
 };
 
 // pub use embedded_graphics::primitives::Rectangle as Rectangle;
 // pub use embedded_graphics::geometry::Point as Point;
 // pub use embedded_graphics::geometry::Size as Size;
 
+#[cfg(feature = "antialiasing")]
+pub mod antialias;
+pub mod easing;
+pub mod focus;
+pub mod overflow;
+pub mod rotation;
+pub mod text;
+#[cfg(test)]
+mod testing;
 pub mod themes;
 pub mod widgets;
 pub mod prelude;
 
 extern crate alloc;
 
-pub(crate) static WIDGET_IDS: AtomicUsize = core::sync::atomic::AtomicUsize::new(0);
-
 pub const MAX_SIZE: Size = Size::new(u32::MAX, u32::MAX);
 pub const MIN_SIZE: Size = Size::zero();
 
@@ -52,7 +61,11 @@ impl Default for DebugOptions {
     }
 }
 
-/// Event result struct
+/// Event result struct.
+///
+/// Propagation policy of every container: children are drawn in order, and once a child returns
+/// [EventResult::Stop] the later siblings are drawn with [SystemEvent::Idle], so they do not act
+/// on the same event. The container then returns `Stop` too, stopping its own later siblings
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum EventResult {
     /// Event processed
@@ -86,6 +99,25 @@ impl SystemEvent {
     fn is_motion_event(&self) -> bool {
         matches!(self, SystemEvent::FocusTo(_) | SystemEvent::Move(_))
     }
+
+    /// Returns `true` for events coming from a pointer (mouse, touch), `false` for keyboard and
+    /// encoder navigation
+    fn is_pointer_event(&self) -> bool {
+        matches!(
+            self,
+            SystemEvent::Active(_) | SystemEvent::Move(_) | SystemEvent::Drag(_)
+        )
+    }
+
+    /// Returns the pointer position of a pointer event
+    fn point(&self) -> Option<Point> {
+        match *self {
+            SystemEvent::Active(point) | SystemEvent::Move(point) | SystemEvent::Drag(point) => {
+                Some(point)
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Filtered to specified widget event
@@ -100,7 +132,15 @@ pub enum Event {
     Drag(Point),
 }
 
+/// Overlay queued with [UiContext::push_overlay], `true` if it is modal
+type Overlay<'a, D, C> = (Rectangle, WidgetObject<'a, D, C>, bool);
+
 /// Primary UI Context
+///
+/// Use one context per display. Contexts share nothing except the [Theme] value they were
+/// created from (themes are `Copy`): each one counts its own widget ids and keeps its own focus
+/// and overlays, so the UIs can be built and updated in any order, see
+/// `examples/dual_display.rs`
 pub struct UiContext<'a, D, C>
 where
     D: DrawTarget<Color = C> + 'a,
@@ -112,11 +152,66 @@ where
     pub theme: Theme<C>,
     /// Event to pass in the library
     motion_event: SystemEvent,
+    /// A motion event was pushed since the last frame
+    motion_pushed: bool,
+    /// Motion pushed along with the interaction of this frame, widgets missed by the interaction
+    /// handle it instead (for the hover styling)
+    pub(crate) hover_event: SystemEvent,
+    /// Frames without input and the dim color, see [UiContext::set_idle_dimmer]
+    idle_dimmer: Option<(u32, C)>,
+    /// Frames drawn since the last input
+    idle_frames: u32,
     interaction_event: SystemEvent,
     debug_options: Rc<RefCell<DebugOptions>>,
     alert_text: Rc<RefCell<String>>,
-    elements_count: usize,
+    frame_counter: u32,
+    focus_indicator: Option<FocusIndicator>,
+    /// Shows the focus indicator only after keyboard/encoder navigation, like CSS `:focus-visible`
+    focus_visible: bool,
+    /// Last input came from a pointer
+    pointer_input: bool,
+    accelerators: Vec<(u32, usize)>,
+    /// Ids and rects of the interactive widgets drawn this frame, see
+    /// [UiContext::interactive_widgets]
+    pub(crate) interactive: Vec<(usize, Rectangle)>,
+    /// Pointer movement (in pixels) after a press which is ignored, see [UiContext::set_touch_slop]
+    touch_slop: u32,
+    /// Frames after a press in which another press nearby is dropped
+    tap_debounce: u32,
+    /// Point and frame of the last accepted press
+    last_press: Option<(Point, u32)>,
+    /// Press point while the pointer has not left the touch slop area
+    slop_origin: Option<Point>,
+    /// Nesting level of the widget currently measured, laid out or drawn
+    depth: usize,
+    /// Widgets nested deeper are skipped, see [UiContext::set_max_depth]
+    max_depth: Option<usize>,
+    /// Popups queued for this frame, see [UiContext::push_overlay]. The context owns widgets
+    /// borrowing `'a`, so the state borrowed by the widgets must be declared before the context
+    overlays: Vec<Overlay<'a, D, C>>,
+    /// Modal overlays were shown in the previous frame, so they take the input
+    overlay_shown: bool,
+    /// Last widget id handed out, see [UiContext::next_widget_id]
+    widget_ids: usize,
+    /// Widget ids are not reset every frame, see [UiContext::set_retained_ids]
+    retained_ids: bool,
+    /// Measured text sizes keyed by the text hash, text length and font id, with the last use
+    /// stamp. Kept between frames, see [UiContext::set_text_cache]
+    text_cache: Vec<((u64, usize, usize), Size, u32)>,
+    text_cache_capacity: usize,
+    text_cache_hits: u32,
+    /// Stamp of the last text cache use, the least recently used entry has the oldest one
+    text_cache_clock: u32,
+    rotation: Rotation,
+    /// Part of the draw target used by the UI, see [UiContext::set_viewport]
+    viewport: Option<Rectangle>,
+    pub(crate) overflow_tracker: Option<Rc<RefCell<OverflowTracker>>>,
+    pub(crate) focused_rect: Option<Rectangle>,
     pub(crate) focused_element: usize,
+    /// Background color of the innermost layout being drawn, see [UiContext::background_color]
+    pub(crate) background: Option<C>,
+    /// Widgets being drawn are inside a disabled layout, see [UiContext::is_disabled]
+    pub(crate) disabled: bool,
     marker: PhantomData<&'a C>,
 }
 
@@ -128,12 +223,41 @@ where
     /// Creates a new UI context with specified `DrawTaget` and `Theme`
     pub fn new(draw_target: D, theme: Theme<C>) -> Self {
         Self {
-            elements_count: 0,
+            frame_counter: 0,
+            focus_indicator: None,
+            focus_visible: false,
+            pointer_input: false,
+            accelerators: Vec::new(),
+            interactive: Vec::new(),
+            touch_slop: 0,
+            tap_debounce: 0,
+            last_press: None,
+            slop_origin: None,
+            depth: 0,
+            max_depth: None,
+            overlays: Vec::new(),
+            overlay_shown: false,
+            widget_ids: 0,
+            retained_ids: false,
+            text_cache: Vec::new(),
+            text_cache_capacity: 0,
+            text_cache_hits: 0,
+            text_cache_clock: 0,
+            rotation: Rotation::Deg0,
+            viewport: None,
+            overflow_tracker: None,
+            focused_rect: None,
             draw_target,
             theme,
             motion_event: SystemEvent::Idle,
+            motion_pushed: false,
+            hover_event: SystemEvent::Idle,
+            idle_dimmer: None,
+            idle_frames: 0,
             interaction_event: SystemEvent::Idle,
             focused_element: 0,
+            background: None,
+            disabled: false,
             debug_options: Rc::new(RefCell::new(DebugOptions::default())),
             alert_text: Rc::new(RefCell::new(String::new())),
             marker: PhantomData,
@@ -141,35 +265,346 @@ where
     }
 
     pub fn push_event(&mut self, event: SystemEvent) {
+        let event = self.rotate_event(event);
+        if self.is_touch_noise(event) {
+            return;
+        }
+
+        if event != SystemEvent::Idle {
+            self.pointer_input = event.is_pointer_event();
+        }
+
         if event.is_motion_event() {
             self.motion_event = event;
+            self.motion_pushed = true;
         } else {
             self.interaction_event = event;
         }
     }
 
+    /// Pushes all inputs gathered since the last frame, like [UiContext::push_event] for each one
+    /// in order. The context keeps one motion event ([SystemEvent::FocusTo], [SystemEvent::Move])
+    /// and one interaction event (the rest), the last one of each kind wins. `Idle` entries are
+    /// skipped, so they do not drop an earlier interaction. The next frame handles the interaction
+    /// if there is one, the widgets it misses get the motion event (for the hover styling). The
+    /// motion event stays for the following frames
+    pub fn push_events(&mut self, events: &[SystemEvent]) {
+        for event in events {
+            if *event != SystemEvent::Idle {
+                self.push_event(*event);
+            }
+        }
+    }
+
+    /// Returns the pending interaction event and clears it, so the next frame does not handle
+    /// it. `SystemEvent::Idle` means there was none or the last frame already handled it. Useful
+    /// to drop a press which already closed a modal handled by the application
+    pub fn take_interaction_event(&mut self) -> SystemEvent {
+        core::mem::replace(&mut self.interaction_event, SystemEvent::Idle)
+    }
+
+    /// Limits widget nesting: widgets nested deeper than `depth` levels are not measured, laid
+    /// out or drawn. Protects small MCU stacks from runaway recursion. Skipped widgets are
+    /// outlined with the overflow debug color in debug mode. `None` (default) disables the limit
+    pub fn set_max_depth(&mut self, depth: Option<usize>) {
+        self.max_depth = depth;
+    }
+
+    /// Enters one nesting level, returns `false` (without entering) past the depth limit
+    pub(crate) fn enter_widget(&mut self) -> bool {
+        if self.max_depth.is_some_and(|max_depth| self.depth >= max_depth) {
+            return false;
+        }
+
+        self.depth += 1;
+        true
+    }
+
+    pub(crate) fn leave_widget(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+
+    /// Keeps widget ids across frames for retained widgets. By default ids restart every frame,
+    /// which suits a tree rebuilt every frame: the same widget gets the same id as long as the
+    /// tree does not change. In retained mode ids are assigned once ([WidgetObject::assign_id]
+    /// keeps an assigned id) and never reused, so widgets kept between frames keep their ids.
+    /// The tradeoff: widgets created every frame get new ids every frame, so focus is lost.
+    /// Do not mix both styles
+    pub fn set_retained_ids(&mut self, retained: bool) {
+        self.retained_ids = retained;
+    }
+
+    /// Returns the next free widget id of this context
+    pub(crate) fn next_widget_id(&mut self) -> usize {
+        self.widget_ids += 1;
+        self.widget_ids
+    }
+
+    /// Ends the frame for the widget ids: the next frame counts them from the start again,
+    /// unless [UiContext::set_retained_ids] is set
+    fn restart_widget_ids(&mut self) {
+        if !self.retained_ids {
+            self.widget_ids = 0;
+        }
+    }
+
+    /// Caches up to `capacity` measured text sizes (see [UiContext::measure_text]), so labels
+    /// measured several times per frame with large fonts are measured once. Texts are identified
+    /// by content hash, so the cache is kept between frames and the least recently used text is
+    /// evicted when it is full. `0` (default) disables it
+    pub fn set_text_cache(&mut self, capacity: usize) {
+        self.text_cache_capacity = capacity;
+        self.text_cache.clear();
+        self.text_cache.shrink_to(capacity);
+    }
+
+    /// Returns the number of text measurements served from the cache since the context was
+    /// created, for tuning the cache capacity
+    pub fn text_cache_hits(&self) -> u32 {
+        self.text_cache_hits
+    }
+
+    /// Returns the size of a single line of `text`, from the text cache if enabled
+    pub fn measure_text<S: TextMeasure>(&mut self, style: &S, text: &str) -> Size {
+        if self.text_cache_capacity == 0 {
+            return style.line_size(text);
+        }
+
+        let key = (fnv1a(text), text.len(), style.font_id());
+        self.text_cache_clock = self.text_cache_clock.wrapping_add(1);
+        let clock = self.text_cache_clock;
+
+        if let Some(entry) = self.text_cache.iter_mut().find(|(entry, ..)| *entry == key) {
+            entry.2 = clock;
+            self.text_cache_hits = self.text_cache_hits.wrapping_add(1);
+            return entry.1;
+        }
+
+        let size = style.line_size(text);
+        if self.text_cache.len() < self.text_cache_capacity {
+            self.text_cache.push((key, size, clock));
+        } else if let Some(oldest) = self
+            .text_cache
+            .iter_mut()
+            .max_by_key(|(.., last_use)| clock.wrapping_sub(*last_use))
+        {
+            *oldest = (key, size, clock);
+        }
+        size
+    }
+
+    /// Ignores pointer movement within `pixels` of the press point, so a noisy (e.g. resistive)
+    /// touchscreen does not turn a tap into a drag. Once the pointer leaves the area, movement is
+    /// reported as usual. `0` disables it
+    pub fn set_touch_slop(&mut self, pixels: u32) {
+        self.touch_slop = pixels;
+    }
+
+    /// Drops presses within the touch slop of the previous press if they come less than `frames`
+    /// frames after it, so a bouncing press is a single tap. `0` disables it
+    pub fn set_tap_debounce(&mut self, frames: u32) {
+        self.tap_debounce = frames;
+    }
+
+    /// Dims the screen with `color` (like [UiContext::dim_screen]) once `frames` frames passed
+    /// without input, to save power and OLED burn-in. Any pushed event other than `Idle` undims it
+    /// from the next frame. `None` (default) disables the dimmer
+    pub fn set_idle_dimmer(&mut self, frames: Option<u32>, color: C) {
+        self.idle_dimmer = frames.map(|frames| (frames, color));
+        self.idle_frames = 0;
+    }
+
+    /// Applies the touch slop and the tap debounce, returns `true` if the event must be dropped
+    fn is_touch_noise(&mut self, event: SystemEvent) -> bool {
+        let slop = self.touch_slop as i32;
+        let near = |point: Point, press: Point| {
+            let offset = point - press;
+            offset.x.abs() <= slop && offset.y.abs() <= slop
+        };
+
+        match event {
+            SystemEvent::Active(point) => {
+                if let Some((press, frame)) = self.last_press {
+                    if near(point, press)
+                        && self.frame_counter.wrapping_sub(frame) < self.tap_debounce
+                    {
+                        return true;
+                    }
+                }
+
+                self.last_press = Some((point, self.frame_counter));
+                self.slop_origin = Some(point);
+                false
+            }
+            SystemEvent::Move(point) | SystemEvent::Drag(point) if slop > 0 => {
+                match self.slop_origin {
+                    Some(origin) if near(point, origin) => true,
+                    _ => {
+                        self.slop_origin = None;
+                        false
+                    }
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Maps input points from physical display coordinates to the rotated UI coordinates
+    fn rotate_event(&self, event: SystemEvent) -> SystemEvent {
+        if self.rotation == Rotation::Deg0 {
+            return event;
+        }
+
+        let physical_size = self
+            .rotation
+            .rotate_size(self.draw_target.bounding_box().size);
+        let map = |point| self.rotation.to_logical(point, physical_size);
+
+        match event {
+            SystemEvent::Active(point) => SystemEvent::Active(map(point)),
+            SystemEvent::Move(point) => SystemEvent::Move(map(point)),
+            SystemEvent::Drag(point) => SystemEvent::Drag(map(point)),
+            event => event,
+        }
+    }
+
+    /// Confines the UI to `viewport` of the draw target, e.g. to leave a status bar to other
+    /// code. The root layout gets the viewport instead of the whole target, so widgets are laid
+    /// out (and input points are expected) in draw target coordinates inside of it. Overlays
+    /// (popups, the slider value bubble) are moved into the viewport too. The viewport is not a
+    /// clip though: widgets draw straight into the draw target, so a widget drawing outside of
+    /// its rect still reaches the rest of the target
+    pub fn set_viewport(&mut self, viewport: Rectangle) {
+        self.viewport = Some(viewport);
+    }
+
+    /// Returns the rect the UI is drawn in, the whole draw target unless
+    /// [UiContext::set_viewport] was called
+    pub fn viewport(&self) -> Rectangle {
+        self.viewport
+            .unwrap_or_else(|| self.draw_target.bounding_box())
+    }
+
+    /// Returns the current theme color for `role`, see [Theme::color]
+    pub fn theme_color(&self, role: ColorRole) -> Option<C> {
+        self.theme.color(role)
+    }
+
     pub fn get_focused_widget_id(&self) -> usize {
         self.focused_element
     }
 
-    /// Cycles to next widget (like Tab key on PC)
-    pub fn next_widget(&mut self) {
-        if self.focused_element >= self.elements_count - 1 {
-            self.focused_element = 1;
+    /// Returns the number of frames drawn by this context. Wraps around on overflow, use it for
+    /// blinking and other time-based effects without keeping state in widgets
+    pub fn frame_counter(&self) -> u32 {
+        self.frame_counter
+    }
+
+    /// Returns the background color behind the widget being drawn: the background of the
+    /// innermost layout which has one, otherwise the layout style background, then the screen
+    /// background [Theme::background_color]
+    pub fn background_color(&self) -> C {
+        self.background
+            .or(self.theme.layout_style.base().background_color)
+            .unwrap_or(self.theme.background_color)
+    }
+
+    /// Returns `true` while drawing the children of a disabled layout (see
+    /// [widgets::linear_layout::LinearLayoutBuilder::disabled]). Such widgets get only idle events
+    /// and are drawn with [Modifier::Disabled]
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Returns the visual state of a widget handling `event`, [Modifier::Disabled] inside a
+    /// disabled layout
+    pub fn modifier(&self, event: &Event) -> Modifier {
+        if self.disabled {
+            Modifier::Disabled
         } else {
-            self.focused_element += 1;
+            Modifier::from(event)
         }
-        self.push_event(SystemEvent::FocusTo(self.focused_element));
     }
 
-    /// Cycles to previous widget (like Shift+Tab key on PC)
+    /// Returns the style of a widget handling `event`: the state of `style` for the
+    /// [UiContext::modifier], inside a disabled layout the idle state with
+    /// [Theme::disabled_style] merged on top. Widgets should resolve their styles here instead of
+    /// [DynamicStyle::style], so they look disabled inside a disabled layout
+    pub fn style(&self, style: &DynamicStyle<C>, event: &Event) -> WidgetStyle<C> {
+        match self.modifier(event) {
+            Modifier::Disabled => style.idle.merge(self.theme.disabled_style),
+            modifier => style.modifier_style(modifier),
+        }
+    }
+
+    /// Enables an animated focus rectangle which slides to the newly focused widget over the
+    /// specified number of frames. `None` disables the indicator
+    pub fn set_focus_animation(&mut self, frames: Option<u32>) {
+        self.focus_indicator = frames.map(FocusIndicator::new);
+    }
+
+    /// Hides the focus indicator while the user interacts with a pointer, it shows up again after
+    /// keyboard or encoder navigation. Mirrors CSS `:focus-visible`, useful for devices with both
+    /// a touch screen and buttons
+    pub fn set_focus_visible(&mut self, enabled: bool) {
+        self.focus_visible = enabled;
+    }
+
+    /// Returns `false` if the focus indicator is hidden because of the last input modality, see
+    /// [UiContext::set_focus_visible]
+    pub fn is_focus_ring_visible(&self) -> bool {
+        !(self.focus_visible && self.pointer_input)
+    }
+
+    /// Cycles to next widget (like Tab key on PC). Walks the interactive widgets drawn in the last
+    /// frame, so widgets of a disabled layout are skipped
+    pub fn next_widget(&mut self) {
+        let ids = self.interactive.iter().map(|(id, _)| *id);
+        let next = ids
+            .clone()
+            .filter(|id| *id > self.focused_element)
+            .min()
+            .or_else(|| ids.min());
+        self.focus_to(next);
+    }
+
+    /// Cycles to previous widget (like Shift+Tab key on PC), see [UiContext::next_widget]
     pub fn previous_widget(&mut self) {
-        if self.focused_element <= 1 {
-            self.focused_element = self.elements_count - 1;
-        } else {
-            self.focused_element -= 1;
+        let ids = self.interactive.iter().map(|(id, _)| *id);
+        let previous = ids
+            .clone()
+            .filter(|id| *id < self.focused_element)
+            .max()
+            .or_else(|| ids.max());
+        self.focus_to(previous);
+    }
+
+    /// Focuses `id`, navigation helpers pass `None` when the last frame had no interactive
+    /// widgets
+    fn focus_to(&mut self, id: Option<usize>) {
+        if let Some(id) = id {
+            self.focused_element = id;
+            self.push_event(SystemEvent::FocusTo(id));
         }
-        self.push_event(SystemEvent::FocusTo(self.focused_element));
+    }
+
+    /// Returns the ids and rects of the interactive widgets drawn in the last frame, in drawing
+    /// order. Widgets of a disabled layout are left out. Useful for custom (e.g. directional)
+    /// navigation with [UiContext::push_event] and [SystemEvent::FocusTo]
+    pub fn interactive_widgets(&self) -> impl Iterator<Item = (usize, Rectangle)> + '_ {
+        self.interactive.iter().copied()
+    }
+
+    /// Focuses the first interactive widget (like Home key on PC). Does nothing if the last frame
+    /// had no interactive widgets
+    pub fn focus_first(&mut self) {
+        self.focus_to(self.interactive.iter().map(|(id, _)| *id).min());
+    }
+
+    /// Focuses the last interactive widget (like End key on PC). Does nothing if the last frame
+    /// had no interactive widgets
+    pub fn focus_last(&mut self) {
+        self.focus_to(self.interactive.iter().map(|(id, _)| *id).max());
     }
 
     /// Activates selected widget (like Enter key on PC)
@@ -177,6 +612,35 @@ where
         self.push_event(SystemEvent::ActiveTo(self.focused_element));
     }
 
+    /// Binds an application defined key code to a widget ID. Pressing the key with
+    /// [UiContext::press_key] activates the widget without navigating to it first, like a press
+    /// the activation then moves the focus to the widget. Rebinding a key replaces the previous
+    /// widget
+    pub fn register_accel(&mut self, key: u32, widget_id: usize) {
+        self.unregister_accel(key);
+        self.accelerators.push((key, widget_id));
+    }
+
+    /// Removes a key binding
+    pub fn unregister_accel(&mut self, key: u32) {
+        self.accelerators.retain(|(bound_key, _)| *bound_key != key);
+    }
+
+    /// Handles a key press. Returns `true` if the key is bound and the widget activation event
+    /// was pushed, otherwise the key is left for the application
+    pub fn press_key(&mut self, key: u32) -> bool {
+        let Some(&(_, widget_id)) = self
+            .accelerators
+            .iter()
+            .find(|(bound_key, _)| *bound_key == key)
+        else {
+            return false;
+        };
+
+        self.push_event(SystemEvent::ActiveTo(widget_id));
+        true
+    }
+
     pub fn dim_screen(&mut self) {
         let modal_style = self.theme.modal_style;
 
@@ -184,12 +648,17 @@ where
             .background_color
             .expect("Modal must have a background color for drawing");
 
-        let bounds = self.draw_target.bounding_box();
+        self.dim_with(modal_background);
+    }
+
+    /// Covers every other pixel of the viewport with `color`
+    fn dim_with(&mut self, color: C) {
+        let bounds = self.viewport();
         let size = bounds.size;
         for x in 0..size.width {
             for y in 0..size.height {
                 if (x + y) % 2 == 0 {
-                    let _ = Pixel(Point::new(x as i32, y as i32), modal_background)
+                    let _ = Pixel(bounds.top_left + Point::new(x as i32, y as i32), color)
                         .draw(&mut self.draw_target);
                 }
             }
@@ -206,6 +675,22 @@ where
         *borrow = String::new();
     }
 
+    /// Queues `widget` to be drawn in `rect` on top of the UI after the rest of the frame. Used
+    /// by popups (e.g. [widgets::dropdown::Dropdown]), which queue the overlay every frame while
+    /// open. While overlays are shown they receive the input exclusively, like an alert. The
+    /// overlay gets presses outside of its rect too (in [WidgetEvent::system_event]), so a popup
+    /// can close on them
+    pub fn push_overlay(&mut self, rect: Rectangle, widget: WidgetObject<'a, D, C>) {
+        self.overlays.push((rect, widget, true));
+    }
+
+    /// Queues `widget` to be drawn on top of the UI like [UiContext::push_overlay], but it never
+    /// receives input and the UI below keeps it. For transient indicators, e.g. the value bubble
+    /// of [widgets::slider::Slider::value_bubble]
+    pub fn push_passive_overlay(&mut self, rect: Rectangle, widget: WidgetObject<'a, D, C>) {
+        self.overlays.push((rect, widget, false));
+    }
+
 
     pub fn toggle_debug_mode(&mut self) {
         let mut debug_options = self.debug_options.borrow_mut();
@@ -220,15 +705,82 @@ where
 
     /// Updates and draws the UI, probably you want run this in main loop
     pub fn update(&mut self, root: WidgetObject<'a, D, C>) {
-        self.elements_count = WIDGET_IDS.load(Ordering::Relaxed);
-        WIDGET_IDS.store(1, Ordering::Relaxed);
-        let bounds = self.draw_target.bounding_box();
+        self.run_frame(root);
+    }
+
+    /// Updates and draws several independent UIs (e.g. a status bar, the main screen and a
+    /// footer) in one frame, each root laid out in its own rect (in draw target coordinates).
+    /// Widget ids, focus and overlays are shared, like in a single tree
+    pub fn update_regions(
+        &mut self,
+        regions: impl IntoIterator<Item = (Rectangle, WidgetObject<'a, D, C>)>,
+    ) {
+        self.run_regions(regions);
+    }
+
+    /// Draws a complete frame: clears the draw target with `clear_color`, runs `draw` (usually
+    /// `|ctx| ctx.update(ui)`) and returns the finished draw target. Present (flush) only the
+    /// returned target, this way a half-drawn frame never reaches the screen
+    pub fn frame(&mut self, clear_color: C, draw: impl FnOnce(&mut Self)) -> &D {
+        let _ = self.draw_target.clear(clear_color);
+        draw(self);
+        &self.draw_target
+    }
+
+    /// Measures, lays out and draws the lone `widget` in `rect` with `event` and returns the
+    /// event handling result. Like a frame of [UiContext::update] it restarts the
+    /// [UiContext::interactive_widgets] and the focused rect and draws the overlays the widget
+    /// queued, but there is no root layout, alert or focus indicator. Handy for widget tests and
+    /// embedding a single control
+    pub fn draw_widget(
+        &mut self,
+        mut widget: WidgetObject<'a, D, C>,
+        rect: Rectangle,
+        event: SystemEvent,
+    ) -> EventResult {
+        self.interactive.clear();
+        self.focused_rect = None;
+        widget.size(self, rect.size);
+        widget.layout(self, rect);
+        let mut event_result = widget.draw(self, &event);
+        if self.draw_overlays(&event, false) == EventResult::Stop {
+            event_result = EventResult::Stop;
+        }
+        self.restart_widget_ids();
+        event_result
+    }
+
+    /// Runs a full frame (layout, event handling and drawing) with the `event` and returns the
+    /// event handling result. Intended for tests that drive the UI with synthetic input
+    pub fn simulate(&mut self, root: WidgetObject<'a, D, C>, event: SystemEvent) -> EventResult {
+        self.push_event(event);
+        self.run_frame(root)
+    }
+
+    fn run_frame(&mut self, root: WidgetObject<'a, D, C>) -> EventResult {
+        let bounds = self.viewport();
+        self.run_regions([(bounds, root)])
+    }
+
+    fn run_regions(
+        &mut self,
+        regions: impl IntoIterator<Item = (Rectangle, WidgetObject<'a, D, C>)>,
+    ) -> EventResult {
+        self.interactive.clear();
+        let bounds = self.viewport();
 
         let alert_shown = !self.alert_text.borrow().is_empty();
         //let debug_options_enaled = self.debug_options.borrow().enabled;
 
         let mut root_layout = RootLayout::new();
-        root_layout.add_widget_obj(root, bounds, !alert_shown, Anchor::TopLeft);
+        for (rect, root) in regions {
+            root_layout.add_widget_obj(
+                root,
+                rect,
+                !alert_shown && !self.overlay_shown,
+                Anchor::TopLeft,
+            );
+        }
 
 
         // if debug_options_enaled {
@@ -260,12 +812,915 @@ where
         let mut root_layout = root_layout.finish();
         root_layout.size(self, bounds.size);
         root_layout.layout(self, bounds);
+        self.focused_rect = None;
+
+        let interaction = self.interaction_event != SystemEvent::Idle;
+        if interaction || self.motion_pushed {
+            self.idle_frames = 0;
+        } else {
+            self.idle_frames = self.idle_frames.saturating_add(1);
+        }
+        // a motion pushed along with the interaction reaches the widgets the interaction misses,
+        // so the hover update is not lost
+        if interaction && self.motion_pushed {
+            self.hover_event = self.motion_event;
+        }
+        self.motion_pushed = false;
+
+        let system_event = if interaction {
+            self.interaction_event
+        } else {
+            self.motion_event
+        };
+
+        let mut event_result = root_layout.draw(self, &system_event);
+        self.hover_event = SystemEvent::Idle;
+        if self.draw_overlays(&system_event, alert_shown) == EventResult::Stop {
+            event_result = EventResult::Stop;
+        }
+
+        // an interaction is handled once, motion stays until the next one
+        if interaction {
+            self.take_interaction_event();
+        }
+
+        self.draw_focus_indicator();
+
+        if let Some((frames, color)) = self.idle_dimmer {
+            if self.idle_frames >= frames {
+                self.dim_with(color);
+            }
+        }
 
-        if self.interaction_event == SystemEvent::Idle {
-            root_layout.draw(self, &self.motion_event.clone());
+        self.restart_widget_ids();
+        self.frame_counter = self.frame_counter.wrapping_add(1);
+        event_result
+    }
+
+    /// Draws the overlays queued during this frame. Overlays get the event only if they were
+    /// already shown in the previous frame, otherwise the click opening a popup would also land
+    /// on it
+    fn draw_overlays(&mut self, system_event: &SystemEvent, alert_shown: bool) -> EventResult {
+        let overlays = core::mem::take(&mut self.overlays);
+        let event = if self.overlay_shown && !alert_shown {
+            system_event
         } else {
-            root_layout.draw(self, &self.interaction_event.clone());
-            self.interaction_event = SystemEvent::Idle;
+            &SystemEvent::Idle
+        };
+
+        let mut event_result = EventResult::Pass;
+        let bounds = self.viewport();
+        self.overlay_shown = overlays.iter().any(|(_, _, modal)| *modal);
+        for (rect, mut overlay, modal) in overlays {
+            let event = if modal { event } else { &SystemEvent::Idle };
+            let rect = move_inside(rect, bounds);
+            overlay.size(self, rect.size);
+            overlay.layout(self, rect);
+            overlay.draw_sibling(self, event, &mut event_result);
+        }
+
+        event_result
+    }
+
+    fn draw_focus_indicator(&mut self) {
+        let ring_visible = self.is_focus_ring_visible();
+        let Some(indicator) = self.focus_indicator.as_mut() else {
+            return;
+        };
+
+        match self.focused_rect {
+            Some(rect) => indicator.retarget(self.focused_element, rect, self.frame_counter),
+            None => indicator.reset(),
+        }
+
+        if indicator.is_visible() && ring_visible {
+            let _ = indicator
+                .rect(self.frame_counter)
+                .into_styled(PrimitiveStyle::with_stroke(
+                    self.theme.focus_color,
+                    self.theme.focus_width,
+                ))
+                .draw(&mut self.draw_target);
+        }
+    }
+}
+
+
+impl<'a, D, C> UiContext<'a, RotatedTarget<D>, C>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    /// Creates a new UI context which renders rotated by `rotation`. Input points pushed with
+    /// [UiContext::push_event] are expected in physical display coordinates
+    pub fn new_rotated(draw_target: D, rotation: Rotation, theme: Theme<C>) -> Self {
+        let mut context = Self::new(RotatedTarget::new(draw_target, rotation), theme);
+        context.rotation = rotation;
+        context
+    }
+
+    /// Changes the rotation for both drawing and input
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.draw_target.set_rotation(rotation);
+        self.rotation = rotation;
+    }
+}
+
+impl<'a, D, C> UiContext<'a, OverflowTarget<D>, C>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    /// Creates a new UI context which checks every drawn pixel against the rect of the drawing
+    /// widget. In debug mode, overflowing widgets are outlined with
+    /// [themes::DebugStyle::debug_rect_overflow] color
+    pub fn new_overflow_checked(draw_target: D, theme: Theme<C>) -> Self {
+        let draw_target = OverflowTarget::new(draw_target);
+        let tracker = draw_target.tracker();
+        let mut context = Self::new(draw_target, theme);
+        context.overflow_tracker = Some(tracker);
+        context
+    }
+}
+
+/// 64-bit FNV-1a hash of `text`, identifies texts in the text cache
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Moves `rect` to lie inside of `bounds`, shrinking it if it is larger
+fn move_inside(rect: Rectangle, bounds: Rectangle) -> Rectangle {
+    let size = rect.size.component_min(bounds.size);
+    let max = bounds.top_left + bounds.size - size;
+    let top_left = rect.top_left.component_min(max);
+    Rectangle::new(top_left.component_max(bounds.top_left), size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{
+            framebuffer_context, mock_context, Block, Framebuffer, IdRecorder, MeasureCounter,
+            Stopper,
+        },
+        themes::{hope_diamond, WidgetStyle},
+        widgets::{
+            button::Button,
+            filler::{FillStrategy, Filler},
+            grid_layout::GridLayoutBuilder,
+            linear_layout::LinearLayoutBuilder,
+            slider::Slider,
+            UiBuilder, Widget, WidgetEvent,
+        },
+    };
+    use core::cell::Cell;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        mono_font::{ascii::FONT_4X6, MonoTextStyle},
+        pixelcolor::Rgb888,
+    };
+
+    #[test]
+    fn accelerator_activates_widget() {
+        let pressed = Cell::new(0);
+        let mut ctx = mock_context::<Rgb888>();
+
+        let mut ui = LinearLayoutBuilder::default();
+        ui.add_widget(Button::new("F2".into(), &FONT_4X6, Box::new(|| {})));
+        let id = ui.add_widget_returning_id(
+            &mut ctx,
+            Button::new(
+                "F1".into(),
+                &FONT_4X6,
+                Box::new(|| pressed.set(pressed.get() + 1)),
+            ),
+        );
+
+        ctx.register_accel(0xF1, id);
+        assert!(!ctx.press_key(0xF2));
+        assert!(ctx.press_key(0xF1));
+        assert_eq!(ctx.get_focused_widget_id(), 0);
+        ctx.update(ui.finish());
+
+        assert_eq!(pressed.get(), 1);
+        // the activation focuses the widget like a press
+        assert_eq!(ctx.get_focused_widget_id(), id);
+    }
+
+    #[test]
+    fn rotated_click_hits_opposite_corner() {
+        let mut display = MockDisplay::<Rgb888>::new();
+        display.set_allow_overdraw(true);
+        let pressed = Cell::new(false);
+        let mut ctx = UiContext::new_rotated(display, Rotation::Deg180, hope_diamond::apply());
+
+        let mut button = WidgetObject::new(Box::new(Button::new(
+            "X".into(),
+            &FONT_4X6,
+            Box::new(|| pressed.set(true)),
+        )));
+        button.layout(
+            &mut ctx,
+            Rectangle::new(Point::new(48, 48), Size::new(16, 16)),
+        );
+
+        ctx.push_event(SystemEvent::Active(Point::new(2, 2)));
+        let event = ctx.interaction_event;
+        button.draw(&mut ctx, &event);
+
+        assert!(pressed.get());
+    }
+
+    /// Draws a single pixel right to its rect, or inside it
+    struct PixelWidget(bool);
+
+    impl<'a, D, C> Widget<'a, D, C> for PixelWidget
+    where
+        D: DrawTarget<Color = C>,
+        C: PixelColor + 'a,
+    {
+        fn draw(
+            &mut self,
+            context: &mut UiContext<'a, D, C>,
+            rect: Rectangle,
+            _event_args: WidgetEvent,
+        ) -> EventResult {
+            let offset = if self.0 { rect.size.width as i32 } else { 0 };
+            let _ = Pixel(rect.top_left + Point::new(offset, 0), context.theme.label_color)
+                .draw(&mut context.draw_target);
+            EventResult::Pass
+        }
+    }
+
+    #[test]
+    fn simulated_click_fires_button() {
+        let pressed = Cell::new(0);
+        let mut ctx = mock_context::<Rgb888>();
+
+        let ui = || {
+            let mut ui = LinearLayoutBuilder::default();
+            ui.add_widget(Button::new(
+                "OK".into(),
+                &FONT_4X6,
+                Box::new(|| pressed.set(pressed.get() + 1)),
+            ));
+            ui.finish()
+        };
+
+        let result = ctx.simulate(ui(), SystemEvent::Active(Point::new(60, 60)));
+        assert_eq!(result, EventResult::Pass);
+        assert_eq!(pressed.get(), 0);
+
+        let result = ctx.simulate(ui(), SystemEvent::Active(Point::new(2, 2)));
+        assert_eq!(result, EventResult::Stop);
+        assert_eq!(pressed.get(), 1);
+    }
+
+    #[test]
+    fn two_contexts_share_one_theme() {
+        let pressed = [Cell::new(0), Cell::new(0)];
+        let theme = hope_diamond::apply::<Rgb888>();
+        let mut first = UiContext::new(MockDisplay::<Rgb888>::new(), theme);
+        let mut second = UiContext::new(MockDisplay::<Rgb888>::new(), theme);
+        first.draw_target.set_allow_overdraw(true);
+        second.draw_target.set_allow_overdraw(true);
+
+        let ui = |counter: usize| {
+            let pressed = &pressed[counter];
+            let mut ui = LinearLayoutBuilder::default();
+            ui.add_widget(Button::new(
+                "OK".into(),
+                &FONT_4X6,
+                Box::new(move || pressed.set(pressed.get() + 1)),
+            ));
+            ui.finish()
+        };
+
+        first.simulate(ui(0), SystemEvent::Active(Point::new(2, 2)));
+        second.update(ui(1));
+        assert_eq!((pressed[0].get(), pressed[1].get()), (1, 0));
+
+        second.simulate(ui(1), SystemEvent::Active(Point::new(2, 2)));
+        first.update(ui(0));
+        assert_eq!((pressed[0].get(), pressed[1].get()), (1, 1));
+        assert_eq!(
+            first.theme_color(ColorRole::Accent),
+            second.theme_color(ColorRole::Accent)
+        );
+    }
+
+    #[test]
+    fn contexts_count_widget_ids_separately() {
+        let mut first = mock_context::<Rgb888>();
+        let mut second = mock_context::<Rgb888>();
+        let button = || Button::new("OK".into(), &FONT_4X6, Box::new(|| {}));
+
+        // both UIs are built before either context draws a frame
+        let mut first_ui = LinearLayoutBuilder::default();
+        let first_ids = [
+            first_ui.add_widget_returning_id(&mut first, button()),
+            first_ui.add_widget_returning_id(&mut first, button()),
+        ];
+        let mut second_ui = LinearLayoutBuilder::default();
+        let second_id = second_ui.add_widget_returning_id(&mut second, button());
+        first.update(first_ui.finish());
+        second.update(second_ui.finish());
+
+        assert_eq!(first_ids, [1, 2]);
+        assert_eq!(second_id, 1);
+        let ids: Vec<_> = second.interactive_widgets().map(|(id, _)| id).collect();
+        assert_eq!(ids, [second_id]);
+    }
+
+    #[test]
+    fn batched_move_and_press_both_apply() {
+        let pressed = Cell::new(0);
+        let mut ctx = mock_context::<Rgb888>();
+
+        let mut ui = LinearLayoutBuilder::default();
+        ui.add_widget(Button::new(
+            "OK".into(),
+            &FONT_4X6,
+            Box::new(|| pressed.set(pressed.get() + 1)),
+        ));
+
+        ctx.push_events(&[
+            SystemEvent::Move(Point::new(9, 5)),
+            SystemEvent::Active(Point::new(4, 4)),
+            SystemEvent::Idle,
+        ]);
+        ctx.update(ui.finish());
+
+        assert_eq!(pressed.get(), 1);
+        assert_eq!(ctx.interaction_event, SystemEvent::Idle);
+        assert_eq!(ctx.motion_event, SystemEvent::Move(Point::new(9, 5)));
+    }
+
+    /// Records the modifier of every event it gets, 8x8 pixels
+    struct EventLog<'a>(&'a RefCell<Vec<Modifier>>);
+
+    impl<'a, D, C> Widget<'a, D, C> for EventLog<'a>
+    where
+        D: DrawTarget<Color = C> + 'a,
+        C: PixelColor + 'a,
+    {
+        fn size(&mut self, _context: &mut UiContext<'a, D, C>, _hint: Size) -> Size {
+            Size::new(8, 8)
         }
+
+        fn draw(
+            &mut self,
+            _context: &mut UiContext<'a, D, C>,
+            _rect: Rectangle,
+            event_args: WidgetEvent,
+        ) -> EventResult {
+            self.0.borrow_mut().push(Modifier::from(event_args.event));
+            EventResult::Pass
+        }
+    }
+
+    #[test]
+    fn motion_and_interaction_share_one_draw_pass() {
+        let clicked = RefCell::new(Vec::new());
+        let hovered = RefCell::new(Vec::new());
+        let mut ctx = mock_context::<Rgb888>();
+        let build = || {
+            let mut ui = LinearLayoutBuilder::default();
+            ui.add_widget(EventLog(&clicked));
+            ui.add_widget(EventLog(&hovered));
+            ui.finish()
+        };
+
+        ctx.push_events(&[
+            SystemEvent::Move(Point::new(4, 12)),
+            SystemEvent::Active(Point::new(4, 4)),
+        ]);
+        ctx.update(build());
+        // every widget is drawn once, the one missed by the click gets the hover
+        assert_eq!(*clicked.borrow(), [Modifier::Active]);
+        assert_eq!(*hovered.borrow(), [Modifier::Focus]);
+
+        // the motion stays for the next frame
+        clicked.borrow_mut().clear();
+        hovered.borrow_mut().clear();
+        ctx.update(build());
+        assert_eq!(*clicked.borrow(), [Modifier::None]);
+        assert_eq!(*hovered.borrow(), [Modifier::Focus]);
+    }
+
+    #[test]
+    fn taking_interaction_event_leaves_context_idle() {
+        let mut ctx = mock_context::<Rgb888>();
+
+        ctx.push_event(SystemEvent::Active(Point::new(2, 2)));
+        assert_eq!(
+            ctx.take_interaction_event(),
+            SystemEvent::Active(Point::new(2, 2))
+        );
+        assert_eq!(ctx.take_interaction_event(), SystemEvent::Idle);
+        assert_eq!(ctx.interaction_event, SystemEvent::Idle);
+    }
+
+    #[test]
+    fn bouncing_press_is_one_tap() {
+        let pressed = Cell::new(0);
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.set_touch_slop(2);
+        ctx.set_tap_debounce(5);
+
+        let ui = || {
+            let mut ui = LinearLayoutBuilder::default();
+            ui.add_widget(Button::new(
+                "OK".into(),
+                &FONT_4X6,
+                Box::new(|| pressed.set(pressed.get() + 1)),
+            ));
+            ui.finish()
+        };
+
+        ctx.simulate(ui(), SystemEvent::Active(Point::new(4, 4)));
+        ctx.simulate(ui(), SystemEvent::Active(Point::new(5, 4)));
+        assert_eq!(pressed.get(), 1);
+
+        // jitter does not register as a move, leaving the slop area does
+        ctx.push_event(SystemEvent::Move(Point::new(6, 5)));
+        assert_eq!(ctx.motion_event, SystemEvent::Idle);
+        ctx.push_event(SystemEvent::Move(Point::new(9, 5)));
+        assert_eq!(ctx.motion_event, SystemEvent::Move(Point::new(9, 5)));
+
+        for _ in 0..5 {
+            ctx.update(ui());
+        }
+        ctx.simulate(ui(), SystemEvent::Active(Point::new(5, 4)));
+        assert_eq!(pressed.get(), 2);
+    }
+
+    #[test]
+    fn stop_prevents_later_siblings_in_every_layout() {
+        let counters = [Cell::new(0), Cell::new(0), Cell::new(0)];
+        let mut ctx = mock_context::<Rgb888>();
+        let bounds = ctx.draw_target.bounding_box();
+
+        let mut linear = LinearLayoutBuilder::default();
+        let mut grid = GridLayoutBuilder::default()
+            .add_column(33)
+            .add_column(33)
+            .add_column(33)
+            .add_row(100);
+        let mut root = RootLayout::new();
+        for counter in &counters {
+            linear.add_widget(Stopper(counter));
+            grid.add_widget(Stopper(counter));
+            root.add_widget_obj(
+                WidgetObject::new(Box::new(Stopper(counter))),
+                bounds,
+                true,
+                Anchor::TopLeft,
+            );
+        }
+
+        for mut layout in [linear.finish(), grid.finish(), root.finish()] {
+            layout.size(&mut ctx, bounds.size);
+            layout.layout(&mut ctx, bounds);
+            let result = layout.draw(&mut ctx, &SystemEvent::Increase(0.1));
+            assert_eq!(result, EventResult::Stop);
+        }
+
+        assert_eq!(counters.each_ref().map(Cell::get), [3, 0, 0]);
+    }
+
+    /// Forwards to a widget object kept by the application between frames
+    struct Retained<'a>(Rc<RefCell<WidgetObject<'a, MockDisplay<Rgb888>, Rgb888>>>);
+
+    impl<'a> Widget<'a, MockDisplay<Rgb888>, Rgb888> for Retained<'a> {
+        fn size(
+            &mut self,
+            context: &mut UiContext<'a, MockDisplay<Rgb888>, Rgb888>,
+            hint: Size,
+        ) -> Size {
+            self.0.borrow_mut().size(context, hint)
+        }
+
+        fn layout(
+            &mut self,
+            context: &mut UiContext<'a, MockDisplay<Rgb888>, Rgb888>,
+            rect: Rectangle,
+        ) {
+            self.0.borrow_mut().layout(context, rect);
+        }
+
+        fn draw(
+            &mut self,
+            context: &mut UiContext<'a, MockDisplay<Rgb888>, Rgb888>,
+            _rect: Rectangle,
+            event_args: WidgetEvent,
+        ) -> EventResult {
+            self.0.borrow_mut().draw(context, event_args.system_event)
+        }
+    }
+
+    #[test]
+    fn retained_widget_keeps_id() {
+        let pressed = Cell::new(0);
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.set_retained_ids(true);
+        let button = Rc::new(RefCell::new(WidgetObject::new(Box::new(Button::new(
+            "Kept".into(),
+            &FONT_4X6,
+            Box::new(|| pressed.set(pressed.get() + 1)),
+        )))));
+        button.borrow_mut().assign_id();
+
+        let mut ui = LinearLayoutBuilder::default();
+        ui.add_widget(Retained(button.clone()));
+        ctx.update(ui.finish());
+        let id = button.borrow().id();
+        assert_ne!(id, 0);
+
+        // a widget created on a later frame and measured first must not take the kept id
+        let mut ui = LinearLayoutBuilder::default();
+        ui.add_widget(Button::new("New".into(), &FONT_4X6, Box::new(|| {})));
+        ui.add_widget(Retained(button.clone()));
+        ctx.simulate(ui.finish(), SystemEvent::ActiveTo(id));
+
+        assert_eq!(button.borrow().id(), id);
+        let ids: Vec<_> = ctx.interactive_widgets().map(|(id, _)| id).collect();
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], id);
+        assert_eq!(ids[1], id);
+        assert_eq!(pressed.get(), 1);
+    }
+
+    #[test]
+    fn frame_clears_before_drawing() {
+        let mut ctx = mock_context::<Rgb888>();
+        let _ = Pixel(Point::new(1, 1), Rgb888::RED).draw(&mut ctx.draw_target);
+
+        let target = ctx.frame(Rgb888::BLACK, |ctx| {
+            assert_eq!(
+                ctx.draw_target.get_pixel(Point::new(1, 1)),
+                Some(Rgb888::BLACK)
+            );
+            let _ = Pixel(Point::new(2, 2), Rgb888::GREEN).draw(&mut ctx.draw_target);
+        });
+
+        assert_eq!(target.get_pixel(Point::new(1, 1)), Some(Rgb888::BLACK));
+        assert_eq!(target.get_pixel(Point::new(2, 2)), Some(Rgb888::GREEN));
+    }
+
+    #[test]
+    fn theme_color_matches_base_style() {
+        let ctx = mock_context::<Rgb888>();
+        let base = ctx.theme.button_style.base();
+
+        assert_eq!(
+            ctx.theme_color(ColorRole::Background),
+            base.background_color
+        );
+        assert!(ctx.theme_color(ColorRole::Background).is_some());
+        assert_eq!(
+            ctx.theme_color(ColorRole::Foreground),
+            base.foreground_color
+        );
+        assert_eq!(
+            ctx.theme_color(ColorRole::Label),
+            Some(ctx.theme.label_color)
+        );
+        assert_eq!(ctx.theme_color(ColorRole::Surface), None);
+    }
+
+    #[test]
+    fn focus_ring_follows_input_modality() {
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.set_focus_animation(Some(1));
+        ctx.set_focus_visible(true);
+        let mut ui = LinearLayoutBuilder::default();
+        ui.add_widget(Button::new("A".into(), &FONT_4X6, Box::new(|| {})));
+        ctx.update(ui.finish());
+
+        let rect = Rectangle::new(Point::new(4, 4), Size::new(8, 8));
+        let ring_drawn = |ctx: &mut UiContext<'_, MockDisplay<Rgb888>, Rgb888>| {
+            ctx.draw_target = MockDisplay::new();
+            ctx.focused_rect = Some(rect);
+            ctx.draw_focus_indicator();
+            ctx.draw_target.get_pixel(rect.top_left) == Some(ctx.theme.focus_color)
+        };
+
+        ctx.push_event(SystemEvent::Move(Point::new(1, 1)));
+        assert!(!ctx.is_focus_ring_visible());
+        assert!(!ring_drawn(&mut ctx));
+
+        ctx.next_widget();
+        assert!(ctx.is_focus_ring_visible());
+        assert!(ring_drawn(&mut ctx));
+
+        ctx.set_focus_visible(false);
+        ctx.push_event(SystemEvent::Move(Point::new(1, 1)));
+        assert!(ring_drawn(&mut ctx));
+    }
+
+    #[test]
+    fn home_and_end_focus_first_and_last_widget() {
+        let mut ctx = mock_context::<Rgb888>();
+
+        // no interactive widgets
+        ctx.focus_first();
+        ctx.focus_last();
+        assert_eq!(ctx.get_focused_widget_id(), 0);
+
+        let mut ui = LinearLayoutBuilder::default();
+        let first = ui.add_widget_returning_id(
+            &mut ctx,
+            Button::new("A".into(), &FONT_4X6, Box::new(|| {})),
+        );
+        let last = ui.add_widget_returning_id(
+            &mut ctx,
+            Button::new("B".into(), &FONT_4X6, Box::new(|| {})),
+        );
+        ctx.update(ui.finish());
+
+        ctx.focus_first();
+        assert_eq!(ctx.get_focused_widget_id(), first);
+        ctx.focus_last();
+        assert_eq!(ctx.get_focused_widget_id(), last);
+    }
+
+    #[test]
+    fn focus_navigation_skips_disabled_widgets() {
+        let mut ctx = mock_context::<Rgb888>();
+        let mut ui = LinearLayoutBuilder::default();
+        let first = ui.add_widget_returning_id(
+            &mut ctx,
+            Button::new("A".into(), &FONT_4X6, Box::new(|| {})),
+        );
+        let mut panel = LinearLayoutBuilder::default().disabled(true);
+        panel.add_widget(Button::new("B".into(), &FONT_4X6, Box::new(|| {})));
+        ui.add_widget_obj(panel.finish());
+        let last = ui.add_widget_returning_id(
+            &mut ctx,
+            Button::new("C".into(), &FONT_4X6, Box::new(|| {})),
+        );
+        ctx.update(ui.finish());
+
+        ctx.focus_first();
+        ctx.next_widget();
+        assert_eq!(ctx.get_focused_widget_id(), last);
+        ctx.previous_widget();
+        assert_eq!(ctx.get_focused_widget_id(), first);
+        ctx.previous_widget();
+        assert_eq!(ctx.get_focused_widget_id(), last);
+        ctx.focus_last();
+        assert_eq!(ctx.get_focused_widget_id(), last);
+    }
+
+    #[test]
+    fn interactive_widgets_lists_drawn_buttons() {
+        let mut ctx = mock_context::<Rgb888>();
+
+        let mut ui = LinearLayoutBuilder::default();
+        let first = ui.add_widget_returning_id(
+            &mut ctx,
+            Button::new("A".into(), &FONT_4X6, Box::new(|| {})),
+        );
+        let second = ui.add_widget_returning_id(
+            &mut ctx,
+            Button::new("B".into(), &FONT_4X6, Box::new(|| {})),
+        );
+        ctx.update(ui.finish());
+
+        // buttons are 4 + 2 * 6 pixels wide and 6 + 2 * 6 pixels high
+        let widgets: Vec<_> = ctx.interactive_widgets().collect();
+        assert_eq!(
+            widgets,
+            [
+                (first, Rectangle::new(Point::zero(), Size::new(16, 18))),
+                (second, Rectangle::new(Point::new(0, 18), Size::new(16, 18))),
+            ]
+        );
+    }
+
+    #[test]
+    fn returned_id_matches_widget_event_id() {
+        let received = Cell::new(0);
+        let mut ctx = mock_context::<Rgb888>();
+
+        let mut ui = LinearLayoutBuilder::default();
+        let id = ui.add_widget_returning_id(&mut ctx, IdRecorder(&received));
+        ctx.update(ui.finish());
+
+        assert_ne!(id, 0);
+        assert_eq!(received.get(), id);
+    }
+
+    #[test]
+    fn children_are_measured_once_per_frame() {
+        let sized = Cell::new(0);
+        let empty = Cell::new(0);
+        let mut ctx = mock_context::<Rgb888>();
+
+        for frame in 1..=2 {
+            let mut ui = LinearLayoutBuilder::default();
+            ui.add_widget(MeasureCounter(&sized, Size::new(10, 10)));
+            ui.add_widget(MeasureCounter(&empty, Size::zero()));
+            ctx.update(ui.finish());
+
+            assert_eq!(sized.get(), frame);
+            assert_eq!(empty.get(), frame);
+        }
+    }
+
+    #[test]
+    fn overflow_is_flagged_in_debug_mode() {
+        let mut display = MockDisplay::<Rgb888>::new();
+        display.set_allow_overdraw(true);
+        let mut ctx = UiContext::new_overflow_checked(display, hope_diamond::apply());
+        let rect = Rectangle::new(Point::new(8, 8), Size::new(8, 8));
+
+        let mut inside = WidgetObject::new(Box::new(PixelWidget(false)));
+        let mut outside = WidgetObject::new(Box::new(PixelWidget(true)));
+        inside.layout(&mut ctx, rect);
+        outside.layout(&mut ctx, rect);
+
+        outside.draw(&mut ctx, &SystemEvent::Idle);
+        assert!(!outside.is_overflowing());
+
+        ctx.toggle_debug_mode();
+        inside.draw(&mut ctx, &SystemEvent::Idle);
+        outside.draw(&mut ctx, &SystemEvent::Idle);
+        assert!(!inside.is_overflowing());
+        assert!(outside.is_overflowing());
+    }
+
+    #[test]
+    fn debug_outline_uses_debug_style() {
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.theme.debug_style.debug_rect = Rgb888::BLUE;
+        ctx.toggle_debug_mode();
+        ctx.debug_options.borrow_mut().widget_rect_active = false;
+
+        let mut widget = WidgetObject::new(Box::new(PixelWidget(false)));
+        widget.layout(&mut ctx, Rectangle::new(Point::new(8, 8), Size::new(8, 8)));
+        widget.draw(&mut ctx, &SystemEvent::Idle);
+
+        assert_eq!(
+            ctx.draw_target.get_pixel(Point::new(15, 8)),
+            Some(Rgb888::BLUE)
+        );
+    }
+
+    #[test]
+    fn viewport_confines_drawing() {
+        let mut ctx = framebuffer_context(Size::new(200, 200));
+        let viewport = Rectangle::new(Point::new(50, 50), Size::new(100, 100));
+        ctx.set_viewport(viewport);
+        // the alert dims the whole UI
+        ctx.alert("Hi");
+
+        let mut ui =
+            LinearLayoutBuilder::default().style(WidgetStyle::new().background_color(Rgb888::RED));
+        ui.add_widget(Filler::new(FillStrategy::Both));
+        ctx.update(ui.finish());
+
+        let outside = Rectangle::new(Point::zero(), Size::new(200, 200))
+            .points()
+            .filter(|&point| !viewport.contains(point))
+            .all(|point| ctx.draw_target.pixel(point) == Some(Rgb888::BLACK));
+        assert!(outside);
+        assert_eq!(ctx.draw_target.pixel(Point::new(50, 51)), Some(Rgb888::RED));
+        assert_ne!(ctx.draw_target.pixel(Point::new(50, 50)), Some(Rgb888::RED));
+        assert!(!ctx.draw_target.is_filled(viewport, Rgb888::BLACK));
+    }
+
+    #[test]
+    fn text_cache_kept_between_frames() {
+        let font = MonoTextStyle::new(&FONT_4X6, Rgb888::WHITE);
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.set_text_cache(2);
+
+        // a new string every frame, like a label rebuilt every frame
+        for _ in 0..3 {
+            let text = String::from("cached");
+            assert_eq!(ctx.measure_text(&font, &text), Size::new(24, 6));
+        }
+        assert_eq!(ctx.text_cache_hits(), 2);
+
+        // the least recently used text is evicted
+        ctx.measure_text(&font, "other");
+        ctx.measure_text(&font, "cached");
+        ctx.measure_text(&font, "third");
+        assert_eq!(ctx.text_cache_hits(), 3);
+        ctx.measure_text(&font, "cached");
+        assert_eq!(ctx.text_cache_hits(), 4);
+        ctx.measure_text(&font, "other");
+        assert_eq!(ctx.text_cache_hits(), 4);
+    }
+
+    #[test]
+    fn overlays_moved_into_viewport() {
+        let mut ctx = framebuffer_context(Size::new(200, 200));
+        let viewport = Rectangle::new(Point::new(50, 50), Size::new(100, 100));
+        ctx.set_viewport(viewport);
+
+        // like the slider bubble above a slider at the top edge
+        let overlay = Rectangle::new(Point::new(140, 40), Size::new(20, 11));
+        ctx.push_passive_overlay(
+            overlay,
+            WidgetObject::new(Box::new(Block(overlay.size, Rgb888::GREEN))),
+        );
+        ctx.update(LinearLayoutBuilder::default().finish());
+
+        let outside = Rectangle::new(Point::zero(), Size::new(200, 200))
+            .points()
+            .filter(|&point| !viewport.contains(point))
+            .all(|point| ctx.draw_target.pixel(point) == Some(Rgb888::BLACK));
+        assert!(outside);
+        let moved = Rectangle::new(Point::new(130, 50), overlay.size);
+        assert!(ctx.draw_target.is_filled(moved, Rgb888::GREEN));
+    }
+
+    #[test]
+    fn regions_draw_into_their_rects() {
+        let mut ctx = framebuffer_context(Size::new(40, 40));
+        let status_bar = Rectangle::new(Point::zero(), Size::new(40, 8));
+        let main = Rectangle::new(Point::new(0, 8), Size::new(40, 32));
+
+        let region = |color| {
+            let mut ui =
+                LinearLayoutBuilder::default().style(WidgetStyle::new().background_color(color));
+            ui.add_widget(Filler::new(FillStrategy::Both));
+            ui.finish()
+        };
+        ctx.update_regions([
+            (status_bar, region(Rgb888::RED)),
+            (main, region(Rgb888::GREEN)),
+        ]);
+
+        assert!(ctx.draw_target.is_filled(status_bar, Rgb888::RED));
+        assert!(ctx.draw_target.is_filled(main, Rgb888::GREEN));
+    }
+
+    #[test]
+    fn screen_dims_after_idle_frames() {
+        let mut ctx = framebuffer_context(Size::new(10, 10));
+        ctx.set_idle_dimmer(Some(3), Rgb888::BLUE);
+        let run_frame = |ctx: &mut UiContext<'_, Framebuffer<Rgb888>, Rgb888>| {
+            ctx.frame(Rgb888::BLACK, |ctx| {
+                ctx.update(LinearLayoutBuilder::default().finish())
+            });
+            ctx.draw_target.pixel(Point::zero()) == Some(Rgb888::BLUE)
+        };
+
+        assert!(!run_frame(&mut ctx));
+        assert!(!run_frame(&mut ctx));
+        assert!(run_frame(&mut ctx));
+        assert!(run_frame(&mut ctx));
+
+        ctx.push_event(SystemEvent::Move(Point::new(5, 5)));
+        assert!(!run_frame(&mut ctx));
+    }
+
+    #[test]
+    fn lone_widget_is_drawn_and_fired() {
+        let pressed = Cell::new(0);
+        let mut ctx = mock_context::<Rgb888>();
+        let rect = Rectangle::new(Point::new(10, 10), Size::new(30, 12));
+        let button = || {
+            WidgetObject::new(Box::new(Button::new(
+                "OK".into(),
+                &FONT_4X6,
+                Box::new(|| pressed.set(pressed.get() + 1)),
+            )))
+        };
+
+        let result = ctx.draw_widget(button(), rect, SystemEvent::Active(Point::new(2, 2)));
+        assert_eq!(result, EventResult::Pass);
+        assert_eq!(pressed.get(), 0);
+
+        let result = ctx.draw_widget(button(), rect, SystemEvent::Active(Point::new(15, 15)));
+        assert_eq!(result, EventResult::Stop);
+        assert_eq!(pressed.get(), 1);
+        assert!(ctx.draw_target.get_pixel(Point::new(10, 10)).is_some());
+    }
+
+    #[test]
+    fn lone_widget_drains_its_overlays() {
+        let mut ctx = mock_context::<Rgb888>();
+        let mut ui = LinearLayoutBuilder::default();
+        ui.add_widget(Button::new("A".into(), &FONT_4X6, Box::new(|| {})));
+        ctx.update(ui.finish());
+
+        // the bubble is queued above the slider while it is dragged
+        let slider = WidgetObject::new(Box::new(
+            Slider::new(0.5, Box::new(|_| {})).value_bubble(&FONT_4X6),
+        ));
+        let rect = Rectangle::new(Point::new(4, 30), Size::new(40, 10));
+        ctx.draw_widget(slider, rect, SystemEvent::Drag(Point::new(24, 34)));
+
+        assert!(ctx.overlays.is_empty());
+        assert!(ctx.draw_target.get_pixel(Point::new(24, 25)).is_some());
+        assert_eq!(ctx.interactive_widgets().count(), 0);
     }
 }
@@ -0,0 +1,92 @@
+//! Debug helper which catches widgets drawing outside of their computed rect. Create the context
+//! with [crate::UiContext::new_overflow_checked], overflowing widgets are outlined in debug mode
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+/// Bounds of the widget currently drawing and its overflow flag
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OverflowTracker {
+    bounds: Option<Rectangle>,
+    overflow: bool,
+}
+
+impl OverflowTracker {
+    /// Starts tracking a widget, returns the parent state for [OverflowTracker::leave]
+    pub(crate) fn enter(&mut self, bounds: Rectangle) -> Self {
+        let parent = *self;
+        self.bounds = Some(bounds);
+        self.overflow = false;
+        parent
+    }
+
+    /// Stops tracking a widget and restores the parent state. Returns `true` if the widget
+    /// drew outside of its bounds
+    pub(crate) fn leave(&mut self, parent: Self) -> bool {
+        let overflow = self.overflow;
+        *self = parent;
+        overflow
+    }
+
+    fn check(&mut self, point: Point) {
+        if let Some(bounds) = self.bounds {
+            if !bounds.contains(point) {
+                self.overflow = true;
+            }
+        }
+    }
+}
+
+/// Draw target wrapper which checks every pixel against the bounds of the drawing widget
+pub struct OverflowTarget<D> {
+    inner: D,
+    tracker: Rc<RefCell<OverflowTracker>>,
+}
+
+impl<D: DrawTarget> OverflowTarget<D> {
+    pub fn new(inner: D) -> Self {
+        Self {
+            inner,
+            tracker: Rc::new(RefCell::new(OverflowTracker::default())),
+        }
+    }
+
+    pub(crate) fn tracker(&self) -> Rc<RefCell<OverflowTracker>> {
+        self.tracker.clone()
+    }
+
+    pub fn inner(&self) -> &D {
+        &self.inner
+    }
+
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+}
+
+impl<D: DrawTarget> Dimensions for OverflowTarget<D> {
+    fn bounding_box(&self) -> Rectangle {
+        self.inner.bounding_box()
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for OverflowTarget<D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let tracker = self.tracker.clone();
+        self.inner.draw_iter(
+            pixels
+                .into_iter()
+                .inspect(move |Pixel(point, _)| tracker.borrow_mut().check(*point)),
+        )
+    }
+}
@@ -1,8 +1,58 @@
+//! Common imports for building a UI, `use edgy::prelude::*;` is enough for a typical app.
+//!
+//! Besides the crate types it re-exports the usual [embedded_graphics] color, geometry and font
+//! types, and the `*Ui` aliases spare the color generic when the display color is fixed:
+//!
+//! ```
+//! use edgy::prelude::*;
+//!
+//! fn settings_ui<'a, D>(volume: f32, on_save: impl FnMut() + 'a) -> Rgb888Ui<'a, D>
+//! where
+//!     D: DrawTarget<Color = Rgb888> + 'a,
+//! {
+//!     let mut ui = LinearLayoutBuilder::default()
+//!         .horizontal_alignment(LayoutAlignment::Center)
+//!         .direction(LayoutDirection::Vertical);
+//!     ui.label("SETTINGS", Alignment::Center, &ascii::FONT_6X10);
+//!     ui.gauge("VOL", volume);
+//!     ui.button("SAVE", &ascii::FONT_6X10, on_save);
+//!     ui.finish()
+//! }
+//!
+//! fn run<D: DrawTarget<Color = Rgb888>>(display: D) {
+//!     let mut ctx = UiContext::new(display, themes::hope_diamond::apply());
+//!     ctx.push_event(SystemEvent::Active(Point::new(10, 10)));
+//!     ctx.update(settings_ui(0.5, || {}));
+//! }
+//! ```
 pub use crate::{
    UiContext,
+   Event,
    EventResult,
    SystemEvent,
    margin,
-   widgets::{UiBuilder, Widget, WidgetObject, WidgetEvent},
-   widgets::linear_layout::{LayoutAlignment, LayoutDirection}
-};
\ No newline at end of file
+   themes::{self, BorderStyle, DynamicStyle, Theme, WidgetStyle},
+   widgets::{Modifier, UiBuilder, Widget, WidgetObject, WidgetEvent},
+   widgets::button::Button,
+   widgets::gauge::{Gauge, GaugeStyle},
+   widgets::grid_layout::GridLayoutBuilder,
+   widgets::label::{Label, LabelOptions},
+   widgets::linear_layout::{LayoutAlignment, LayoutDirection, LinearLayoutBuilder},
+   widgets::margin_layout::{Margin, MarginLayout},
+   widgets::slider::{Slider, SliderStyle},
+   widgets::toggle_button::ToggleButton,
+};
+pub use embedded_graphics::{
+   mono_font::{ascii, MonoFont},
+   pixelcolor::{BinaryColor, Rgb565, Rgb888, RgbColor, WebColors},
+   prelude::{DrawTarget, PixelColor, Point, Size},
+   primitives::Rectangle,
+   text::Alignment,
+};
+
+/// [WidgetObject] for RGB888 displays
+pub type Rgb888Ui<'a, D> = WidgetObject<'a, D, Rgb888>;
+/// [WidgetObject] for RGB565 displays
+pub type Rgb565Ui<'a, D> = WidgetObject<'a, D, Rgb565>;
+/// [WidgetObject] for monochrome displays
+pub type BinaryUi<'a, D> = WidgetObject<'a, D, BinaryColor>;
\ No newline at end of file
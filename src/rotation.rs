@@ -0,0 +1,142 @@
+//! Display rotation for panels mounted at 90°/180°/270°. Wrap the display into [RotatedTarget]
+//! and create the context with [crate::UiContext::new_rotated], the context then maps both
+//! drawing and input points
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+/// Clockwise display rotation
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Rotation {
+    #[default]
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+impl Rotation {
+    fn is_swapped(&self) -> bool {
+        matches!(self, Rotation::Deg90 | Rotation::Deg270)
+    }
+
+    /// Converts a physical display size to the UI size (and vice versa)
+    pub fn rotate_size(&self, size: Size) -> Size {
+        if self.is_swapped() {
+            Size::new(size.height, size.width)
+        } else {
+            size
+        }
+    }
+
+    /// Maps a UI point to a physical display point
+    pub fn to_physical(&self, point: Point, physical_size: Size) -> Point {
+        let (width, height) = (physical_size.width as i32, physical_size.height as i32);
+        match self {
+            Rotation::Deg0 => point,
+            Rotation::Deg90 => Point::new(width - 1 - point.y, point.x),
+            Rotation::Deg180 => Point::new(width - 1 - point.x, height - 1 - point.y),
+            Rotation::Deg270 => Point::new(point.y, height - 1 - point.x),
+        }
+    }
+
+    /// Maps a physical display point (e.g. from touchscreen) to a UI point
+    pub fn to_logical(&self, point: Point, physical_size: Size) -> Point {
+        let (width, height) = (physical_size.width as i32, physical_size.height as i32);
+        match self {
+            Rotation::Deg0 => point,
+            Rotation::Deg90 => Point::new(point.y, width - 1 - point.x),
+            Rotation::Deg180 => Point::new(width - 1 - point.x, height - 1 - point.y),
+            Rotation::Deg270 => Point::new(height - 1 - point.y, point.x),
+        }
+    }
+}
+
+/// Draw target wrapper which rotates everything drawn into the inner display
+pub struct RotatedTarget<D> {
+    inner: D,
+    rotation: Rotation,
+}
+
+impl<D: DrawTarget> RotatedTarget<D> {
+    pub fn new(inner: D, rotation: Rotation) -> Self {
+        Self { inner, rotation }
+    }
+
+    pub fn rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    pub(crate) fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+    }
+
+    pub fn inner(&self) -> &D {
+        &self.inner
+    }
+
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+}
+
+impl<D: DrawTarget> Dimensions for RotatedTarget<D> {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(
+            Point::zero(),
+            self.rotation.rotate_size(self.inner.bounding_box().size),
+        )
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for RotatedTarget<D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let physical_size = self.inner.bounding_box().size;
+        let rotation = self.rotation;
+        self.inner.draw_iter(
+            pixels.into_iter().map(|Pixel(point, color)| {
+                Pixel(rotation.to_physical(point, physical_size), color)
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    #[test]
+    fn points_round_trip() {
+        let size = Size::new(64, 32);
+        let point = Point::new(5, 9);
+        for rotation in [
+            Rotation::Deg0,
+            Rotation::Deg90,
+            Rotation::Deg180,
+            Rotation::Deg270,
+        ] {
+            let physical = rotation.to_physical(point, size);
+            assert!(Rectangle::new(Point::zero(), size).contains(physical));
+            assert_eq!(rotation.to_logical(physical, size), point);
+        }
+    }
+
+    #[test]
+    fn rotated_pixel_lands_in_opposite_corner() {
+        let mut target = RotatedTarget::new(MockDisplay::<BinaryColor>::new(), Rotation::Deg180);
+        let _ = Pixel(Point::zero(), BinaryColor::On).draw(&mut target);
+        assert_eq!(
+            target.inner().get_pixel(Point::new(63, 63)),
+            Some(BinaryColor::On)
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! Test helpers: render a UI into an in-memory framebuffer and inspect it, plus the contexts and
+//! widget fixtures shared by the unit tests
+
+use alloc::{string::String, vec, vec::Vec};
+use core::cell::Cell;
+use embedded_graphics::{
+    mock_display::MockDisplay, pixelcolor::Rgb888, prelude::*, primitives::Rectangle,
+};
+
+use crate::{
+    themes::{hope_diamond, Theme},
+    widgets::{Widget, WidgetEvent, WidgetObject},
+    EventResult, SystemEvent, UiContext,
+};
+
+/// Framebuffer draw target, drawing outside of it is ignored
+pub(crate) struct Framebuffer<C> {
+    size: Size,
+    pixels: Vec<C>,
+}
+
+impl<C: PixelColor> Framebuffer<C> {
+    pub(crate) fn new(size: Size, background: C) -> Self {
+        Self {
+            size,
+            pixels: vec![background; (size.width * size.height) as usize],
+        }
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        (point.x >= 0
+            && point.y >= 0
+            && (point.x as u32) < self.size.width
+            && (point.y as u32) < self.size.height)
+            .then(|| point.y as usize * self.size.width as usize + point.x as usize)
+    }
+
+    pub(crate) fn pixel(&self, point: Point) -> Option<C> {
+        self.index(point).map(|index| self.pixels[index])
+    }
+
+    /// Returns `true` if every pixel of `rect` has `color`
+    pub(crate) fn is_filled(&self, rect: Rectangle, color: C) -> bool {
+        rect.points().all(|point| self.pixel(point) == Some(color))
+    }
+
+    /// Dumps the buffer as text, one line per row. `symbol` maps a color to a character
+    pub(crate) fn ascii(&self, symbol: impl Fn(C) -> char) -> String {
+        let mut dump = String::new();
+        for row in self.pixels.chunks(self.size.width as usize) {
+            dump.extend(row.iter().map(|&color| symbol(color)));
+            dump.push('\n');
+        }
+        dump
+    }
+}
+
+impl<C: PixelColor> OriginDimensions for Framebuffer<C> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<C: PixelColor> DrawTarget for Framebuffer<C> {
+    type Color = C;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let Some(index) = self.index(point) {
+                self.pixels[index] = color;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs one frame of `root` on a black framebuffer of `size` and returns the result
+pub(crate) fn render<'a>(
+    root: WidgetObject<'a, Framebuffer<Rgb888>, Rgb888>,
+    size: Size,
+    theme: Theme<Rgb888>,
+) -> Framebuffer<Rgb888> {
+    let mut ctx = UiContext::new(Framebuffer::new(size, Rgb888::BLACK), theme);
+    ctx.update(root);
+    ctx.draw_target
+}
+
+/// Context with the default theme drawing into a [MockDisplay] which allows overdraw
+pub(crate) fn mock_context<'a, C>() -> UiContext<'a, MockDisplay<C>, C>
+where
+    C: PixelColor + From<Rgb888> + Default,
+{
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    UiContext::new(display, hope_diamond::apply())
+}
+
+/// Context with the default theme drawing into a black [Framebuffer] of `size`
+pub(crate) fn framebuffer_context<'a>(size: Size) -> UiContext<'a, Framebuffer<Rgb888>, Rgb888> {
+    UiContext::new(Framebuffer::new(size, Rgb888::BLACK), hope_diamond::apply())
+}
+
+/// Fixed size block filled with one color
+pub(crate) struct Block(pub Size, pub Rgb888);
+
+impl<'a, D> Widget<'a, D, Rgb888> for Block
+where
+    D: DrawTarget<Color = Rgb888> + 'a,
+{
+    fn size(&mut self, _context: &mut UiContext<'a, D, Rgb888>, _hint: Size) -> Size {
+        self.0
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, Rgb888>,
+        rect: Rectangle,
+        _event_args: WidgetEvent,
+    ) -> EventResult {
+        let _ = context.draw_target.fill_solid(&rect, self.1);
+        EventResult::Pass
+    }
+}
+
+/// Fixed size widget remembering where it was laid out
+pub(crate) struct Fixed<'a>(pub Size, pub &'a Cell<Rectangle>);
+
+impl<'a, D, C> Widget<'a, D, C> for Fixed<'a>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn size(&mut self, _context: &mut UiContext<'a, D, C>, _hint: Size) -> Size {
+        self.0
+    }
+
+    fn layout(&mut self, _context: &mut UiContext<'a, D, C>, rect: Rectangle) {
+        self.1.set(rect);
+    }
+}
+
+/// Fills the available space and remembers where it was laid out
+pub(crate) struct RectRecorder<'a>(pub &'a Cell<Rectangle>);
+
+impl<'a, D, C> Widget<'a, D, C> for RectRecorder<'a>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn layout(&mut self, _context: &mut UiContext<'a, D, C>, rect: Rectangle) {
+        self.0.set(rect);
+    }
+}
+
+/// Counts the `size` calls
+pub(crate) struct MeasureCounter<'a>(pub &'a Cell<u32>, pub Size);
+
+impl<'a, D, C> Widget<'a, D, C> for MeasureCounter<'a>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn size(&mut self, _context: &mut UiContext<'a, D, C>, _hint: Size) -> Size {
+        self.0.set(self.0.get() + 1);
+        self.1
+    }
+}
+
+/// Interactive widget that stores the id it receives in [WidgetEvent]
+pub(crate) struct IdRecorder<'a>(pub &'a Cell<usize>);
+
+impl<'a, D, C> Widget<'a, D, C> for IdRecorder<'a>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn is_interactive(&mut self) -> bool {
+        true
+    }
+
+    fn draw(
+        &mut self,
+        _context: &mut UiContext<'a, D, C>,
+        _rect: Rectangle,
+        event_args: WidgetEvent,
+    ) -> EventResult {
+        self.0.set(event_args.id);
+        EventResult::Pass
+    }
+}
+
+/// Remembers that it was drawn
+pub(crate) struct DrawFlag<'a>(pub &'a Cell<bool>);
+
+impl<'a, D, C> Widget<'a, D, C> for DrawFlag<'a>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn draw(
+        &mut self,
+        _context: &mut UiContext<'a, D, C>,
+        _rect: Rectangle,
+        _event_args: WidgetEvent,
+    ) -> EventResult {
+        self.0.set(true);
+        EventResult::Pass
+    }
+}
+
+/// Counts the events it gets and stops every one of them
+pub(crate) struct Stopper<'a>(pub &'a Cell<u32>);
+
+impl<'a, D, C> Widget<'a, D, C> for Stopper<'a>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn draw(
+        &mut self,
+        _context: &mut UiContext<'a, D, C>,
+        _rect: Rectangle,
+        event_args: WidgetEvent,
+    ) -> EventResult {
+        if *event_args.system_event == SystemEvent::Idle {
+            return EventResult::Pass;
+        }
+
+        self.0.set(self.0.get() + 1);
+        EventResult::Stop
+    }
+}
@@ -0,0 +1,157 @@
+//! Text styles of the text widgets
+use embedded_graphics::{
+    mono_font::{MonoFont, MonoTextStyle},
+    prelude::*,
+    text::{
+        renderer::{CharacterStyle, TextRenderer},
+        Baseline,
+    },
+};
+
+/// Text style of the text widgets ([crate::widgets::label::Label],
+/// [crate::widgets::button::Button]): measures the text for layout and takes the theme colors
+/// through [CharacterStyle]. Implemented for [MonoTextStyle], implement it for another
+/// [TextRenderer] (e.g. a proportional font) to draw widget text with it. The width comes from
+/// the renderer glyph advances, so proportional fonts report their real width instead of
+/// `char_count * character_width` of monospace fonts
+pub trait TextMeasure:
+    TextRenderer + CharacterStyle<Color = <Self as TextRenderer>::Color> + Clone
+{
+    /// Returns the size of a single line of `text`
+    fn line_size(&self, text: &str) -> Size {
+        self.measure_string(text, Point::zero(), Baseline::Top)
+            .bounding_box
+            .size
+    }
+
+    /// Returns a value identifying the font, measured sizes are cached per font (see
+    /// [crate::UiContext::set_text_cache])
+    fn font_id(&self) -> usize;
+
+    /// Returns the text color, `None` for the theme color
+    fn text_color(&self) -> Option<<Self as TextRenderer>::Color>;
+
+    /// Returns the text background color, `None` for a transparent background
+    fn background_color(&self) -> Option<<Self as TextRenderer>::Color>;
+}
+
+impl<C: PixelColor> TextMeasure for MonoTextStyle<'_, C> {
+    fn font_id(&self) -> usize {
+        self.font as *const MonoFont as usize
+    }
+
+    fn text_color(&self) -> Option<C> {
+        self.text_color
+    }
+
+    fn background_color(&self) -> Option<C> {
+        self.background_color
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::mock_context,
+        widgets::{
+            label::{Label, LabelOptions},
+            Widget,
+        },
+    };
+    use embedded_graphics::{
+        mono_font::ascii::FONT_6X10, pixelcolor::Rgb888, primitives::Rectangle,
+        text::renderer::TextMetrics,
+    };
+
+    /// Proportional font: `i` and `l` are narrow, other glyphs are 6 pixels wide
+    #[derive(Clone, Default)]
+    struct ProportionalFont {
+        text_color: Option<Rgb888>,
+    }
+
+    impl ProportionalFont {
+        fn advance(c: char) -> u32 {
+            match c {
+                'i' | 'l' => 2,
+                _ => 6,
+            }
+        }
+    }
+
+    impl CharacterStyle for ProportionalFont {
+        type Color = Rgb888;
+
+        fn set_text_color(&mut self, text_color: Option<Rgb888>) {
+            self.text_color = text_color;
+        }
+    }
+
+    impl TextRenderer for ProportionalFont {
+        type Color = Rgb888;
+
+        fn draw_string<D: DrawTarget<Color = Rgb888>>(
+            &self,
+            text: &str,
+            position: Point,
+            _baseline: Baseline,
+            _target: &mut D,
+        ) -> Result<Point, D::Error> {
+            let width: u32 = text.chars().map(Self::advance).sum();
+            Ok(position + Point::new(width as i32, 0))
+        }
+
+        fn draw_whitespace<D: DrawTarget<Color = Rgb888>>(
+            &self,
+            width: u32,
+            position: Point,
+            _baseline: Baseline,
+            _target: &mut D,
+        ) -> Result<Point, D::Error> {
+            Ok(position + Point::new(width as i32, 0))
+        }
+
+        fn measure_string(&self, text: &str, position: Point, _baseline: Baseline) -> TextMetrics {
+            let width: u32 = text.chars().map(Self::advance).sum();
+            TextMetrics {
+                bounding_box: Rectangle::new(position, Size::new(width, 10)),
+                next_position: position + Point::new(width as i32, 0),
+            }
+        }
+
+        fn line_height(&self) -> u32 {
+            10
+        }
+    }
+
+    impl TextMeasure for ProportionalFont {
+        fn font_id(&self) -> usize {
+            1
+        }
+
+        fn text_color(&self) -> Option<Rgb888> {
+            self.text_color
+        }
+
+        fn background_color(&self) -> Option<Rgb888> {
+            None
+        }
+    }
+
+    #[test]
+    fn proportional_width_differs_from_fixed_width() {
+        let text = "illicit";
+        let proportional = ProportionalFont::default().line_size(text);
+        assert_eq!(proportional, Size::new(22, 10));
+        assert_ne!(proportional.width, text.len() as u32 * 6);
+
+        let monospace = MonoTextStyle::new(&FONT_6X10, Rgb888::WHITE).line_size(text);
+        assert_eq!(monospace, Size::new(text.len() as u32 * 6, 10));
+
+        // labels measure with their text style
+        let mut ctx = mock_context::<Rgb888>();
+        let mut label =
+            Label::new_with_style(text, LabelOptions::new(), ProportionalFont::default());
+        assert_eq!(label.size(&mut ctx, Size::new(64, 64)), proportional);
+    }
+}
@@ -0,0 +1,109 @@
+//! Circle geometry shared by the dial widgets (e.g. [crate::widgets::gauge::Gauge]). Angles are
+//! in degrees on the screen: 0 points right and the angles grow clockwise, like [Angle]
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Arc, Circle, PrimitiveStyle},
+};
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
+/// Returns the point `distance` away from `center` at `angle`, without rounding
+pub fn polar(center: Point, distance: f32, angle: f32) -> (f32, f32) {
+    let angle = angle.to_radians();
+    (
+        center.x as f32 + distance * angle.cos(),
+        center.y as f32 + distance * angle.sin(),
+    )
+}
+
+/// Returns the pixel `distance` away from `center` at `angle`
+pub fn polar_point(center: Point, distance: f32, angle: f32) -> Point {
+    let (x, y) = polar(center, distance, angle);
+    Point::new(x.round() as i32, y.round() as i32)
+}
+
+/// Returns the outer and the inner end of a tick of `length` pointing to the center, with the
+/// outer end on the circle of `radius`
+pub fn tick_endpoints(center: Point, radius: f32, length: f32, angle: f32) -> (Point, Point) {
+    (
+        polar_point(center, radius, angle),
+        polar_point(center, radius - length, angle),
+    )
+}
+
+/// Draws the arc of the circle with `center` and `diameter` from `start` over `sweep` degrees
+pub fn draw_arc<D, C>(
+    target: &mut D,
+    center: Point,
+    diameter: u32,
+    start: f32,
+    sweep: f32,
+    style: PrimitiveStyle<C>,
+) where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    let _ = Arc::with_center(
+        center,
+        diameter,
+        Angle::from_degrees(start),
+        Angle::from_degrees(sweep),
+    )
+    .into_styled(style)
+    .draw(target);
+}
+
+/// Draws a round cap of `width` on the outline of the circle with `center` and `diameter` at
+/// `angle`. Capping both ends of a stroked arc lets adjacent arcs join without a gap
+pub fn draw_round_cap<D, C>(
+    target: &mut D,
+    center: Point,
+    diameter: u32,
+    angle: f32,
+    width: u32,
+    color: C,
+) where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    let _ = Circle::with_center(polar_point(center, diameter as f32 / 2.0, angle), width)
+        .into_styled(PrimitiveStyle::with_fill(color))
+        .draw(target);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tick_endpoints_at_right_angles() {
+        let center = Point::new(10, 10);
+        let cases = [
+            (0.0, (20, 10), (16, 10)),
+            (90.0, (10, 20), (10, 16)),
+            (180.0, (0, 10), (4, 10)),
+            (270.0, (10, 0), (10, 4)),
+        ];
+
+        for (angle, outer, inner) in cases {
+            assert_eq!(
+                tick_endpoints(center, 10.0, 4.0, angle),
+                (Point::from(outer), Point::from(inner)),
+                "angle {angle}"
+            );
+        }
+    }
+
+    #[test]
+    fn diagonal_tick_endpoints() {
+        assert_eq!(
+            tick_endpoints(Point::zero(), 10.0, 4.0, 45.0),
+            (Point::new(7, 7), Point::new(4, 4))
+        );
+        assert_eq!(
+            tick_endpoints(Point::zero(), 10.0, 4.0, -135.0),
+            (Point::new(-7, -7), Point::new(-4, -4))
+        );
+    }
+}
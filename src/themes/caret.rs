@@ -0,0 +1,79 @@
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+/// Blinking text caret (cursor) for text widgets, driven by [crate::UiContext::frame_counter]
+#[derive(Clone, Copy, Debug)]
+pub struct Caret<C: PixelColor> {
+    pub color: C,
+    /// Bar width
+    pub width: u32,
+    /// Number of frames the caret stays visible, and then hidden
+    pub blink_frames: u32,
+}
+
+impl<C: PixelColor> Caret<C> {
+    pub const fn new(color: C) -> Self {
+        Self {
+            color,
+            width: 1,
+            blink_frames: 15,
+        }
+    }
+
+    pub const fn width(mut self, width: u32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub const fn blink_frames(mut self, frames: u32) -> Self {
+        self.blink_frames = frames;
+        self
+    }
+
+    /// Returns `true` if the caret is shown at `frame`. Blinking starts in the visible phase
+    pub fn is_visible(&self, frame: u32) -> bool {
+        (frame / self.blink_frames.max(1)) & 1 == 0
+    }
+
+    /// Draws a vertical bar of `height` with the top left corner at `position` if the caret is
+    /// visible at `frame`. Returns `true` if the caret was drawn
+    pub fn draw<D: DrawTarget<Color = C>>(
+        &self,
+        target: &mut D,
+        frame: u32,
+        position: Point,
+        height: u32,
+    ) -> bool {
+        if !self.is_visible(frame) {
+            return false;
+        }
+
+        let bar = Rectangle::new(position, Size::new(self.width, height));
+        let _ = target.fill_solid(&bar, self.color);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    #[test]
+    fn single_frame_blink() {
+        let caret = Caret::new(BinaryColor::On).blink_frames(1);
+
+        for frame in 0..4 {
+            let mut display = MockDisplay::new();
+            let drawn = caret.draw(&mut display, frame, Point::new(2, 1), 6);
+            let pixel = display.get_pixel(Point::new(2, 4));
+
+            if frame % 2 == 0 {
+                assert!(drawn);
+                assert_eq!(pixel, Some(BinaryColor::On));
+            } else {
+                assert!(!drawn);
+                assert_eq!(pixel, None);
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use embedded_graphics::{
+    pixelcolor::{raw::RawData, Rgb888},
+    prelude::*,
+    primitives::Rectangle,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GradientDirection {
+    /// From the top row to the bottom row
+    Vertical,
+    /// From the left column to the right column
+    Horizontal,
+}
+
+/// Two-color linear gradient for backgrounds. Ignored on monochrome displays
+#[derive(Clone, Copy, Debug)]
+pub struct Gradient<C: PixelColor> {
+    pub from: C,
+    pub to: C,
+    pub direction: GradientDirection,
+    blend: fn(C, C, f32) -> C,
+}
+
+impl<C> Gradient<C>
+where
+    C: PixelColor + From<Rgb888> + Into<Rgb888>,
+{
+    pub fn new(from: C, to: C, direction: GradientDirection) -> Self {
+        Self {
+            from,
+            to,
+            direction,
+            blend,
+        }
+    }
+}
+
+impl<C: PixelColor> Gradient<C> {
+    /// Returns the gradient color at position in range 0.0-1.0
+    pub fn color(&self, position: f32) -> C {
+        (self.blend)(self.from, self.to, position.clamp(0.0, 1.0))
+    }
+
+    /// Fills `rect` with the gradient line by line. Returns `false` without drawing on
+    /// monochrome displays
+    pub fn draw<D: DrawTarget<Color = C>>(&self, target: &mut D, rect: Rectangle) -> bool {
+        if C::Raw::BITS_PER_PIXEL <= 1 {
+            return false;
+        }
+
+        let lines = match self.direction {
+            GradientDirection::Vertical => rect.size.height,
+            GradientDirection::Horizontal => rect.size.width,
+        };
+
+        for line in 0..lines {
+            let color = self.color(line as f32 / lines.saturating_sub(1).max(1) as f32);
+            let line_rect = match self.direction {
+                GradientDirection::Vertical => Rectangle::new(
+                    rect.top_left + Point::new(0, line as i32),
+                    Size::new(rect.size.width, 1),
+                ),
+                GradientDirection::Horizontal => Rectangle::new(
+                    rect.top_left + Point::new(line as i32, 0),
+                    Size::new(1, rect.size.height),
+                ),
+            };
+            let _ = target.fill_solid(&line_rect, color);
+        }
+
+        true
+    }
+}
+
+fn blend<C: From<Rgb888> + Into<Rgb888>>(from: C, to: C, t: f32) -> C {
+    let (from, to): (Rgb888, Rgb888) = (from.into(), to.into());
+    let lerp = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * t + 0.5) as u8;
+    Rgb888::new(
+        lerp(from.r(), to.r()),
+        lerp(from.g(), to.g()),
+        lerp(from.b(), to.b()),
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    #[test]
+    fn vertical_gradient_rows_differ() {
+        let mut display = MockDisplay::<Rgb888>::new();
+        let rect = Rectangle::new(Point::zero(), Size::new(4, 8));
+        let gradient = Gradient::new(Rgb888::BLACK, Rgb888::WHITE, GradientDirection::Vertical);
+
+        assert!(gradient.draw(&mut display, rect));
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb888::BLACK));
+        assert_eq!(display.get_pixel(Point::new(3, 7)), Some(Rgb888::WHITE));
+        assert_ne!(
+            display.get_pixel(Point::new(0, 0)),
+            display.get_pixel(Point::new(0, 4))
+        );
+    }
+
+    #[test]
+    fn monochrome_is_noop() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let gradient = Gradient::new(
+            BinaryColor::Off,
+            BinaryColor::On,
+            GradientDirection::Horizontal,
+        );
+
+        assert!(!gradient.draw(&mut display, Rectangle::new(Point::zero(), Size::new(4, 4))));
+        assert_eq!(display.affected_area(), Rectangle::zero());
+    }
+}
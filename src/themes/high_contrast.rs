@@ -0,0 +1,117 @@
+//! High contrast theme: white on black with thick strokes and a wide focus ring. Meant for
+//! low-vision users and sunlight-readable panels
+use embedded_graphics::{
+    pixelcolor::Rgb888,
+    prelude::{PixelColor, RgbColor, Size},
+};
+
+use crate::widgets::{plot::PlotStyle, slider::SliderStyle};
+
+use super::{ColorTheme, DynamicStyle, Theme, WidgetStyle};
+
+const HIGH_CONTRAST_COLORS: ColorTheme = ColorTheme {
+    background: Rgb888::BLACK,
+    background2: Rgb888::new(64, 64, 64),
+    background3: Rgb888::new(128, 128, 128),
+    foreground: Rgb888::WHITE,
+    foreground2: Rgb888::new(224, 224, 224),
+    foreground3: Rgb888::new(192, 192, 192),
+    accent: Rgb888::YELLOW,
+    success: Rgb888::new(0, 255, 0),
+    warning: Rgb888::YELLOW,
+    debug_rect: Rgb888::RED,
+};
+
+pub fn apply<C: PixelColor + From<Rgb888> + Default>() -> Theme<C> {
+    let colors = HIGH_CONTRAST_COLORS.into_color::<C>();
+    let button_style = WidgetStyle::default()
+        .background_color(colors.background)
+        .foreground_color(colors.foreground)
+        .storke(3, colors.foreground)
+        .accent_color(colors.accent);
+
+    // pressed buttons are inverted instead of getting a slightly different shade
+    let inverted_style = button_style
+        .background_color(colors.foreground)
+        .foreground_color(colors.background);
+
+    let mut theme = Theme::from_colors(colors);
+    theme.button_style = DynamicStyle {
+        idle: button_style,
+        focus: button_style.storke(3, colors.accent),
+        active: inverted_style,
+        drag: inverted_style,
+    };
+    theme.disabled_style = WidgetStyle::new()
+        .foreground_color(colors.background3)
+        .storke(3, colors.background3);
+    theme.slider_style = SliderStyle::new(
+        button_style.into(),
+        button_style.into(),
+        3,
+        Size::new(6, 12),
+    );
+    theme.debug_style.debug_rect_active = colors.success;
+    theme.gauge_style = theme
+        .gauge_style
+        .accent_color(colors.accent)
+        .storke(3, colors.foreground);
+    theme.modal_style = theme.modal_style.storke(3, colors.foreground);
+    theme.progress_style = theme
+        .progress_style
+        .background_color(colors.background3)
+        .accent_color(colors.accent)
+        .storke(4, colors.background3);
+    theme.plot_style = PlotStyle::new(
+        colors.background,
+        colors.background2,
+        colors.foreground3,
+        colors.accent,
+    );
+    theme.focus_width = 3;
+    theme
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use micromath::F32Ext;
+
+    /// WCAG relative luminance
+    fn luminance(color: Rgb888) -> f32 {
+        let channel = |value: u8| {
+            let value = value as f32 / 255.0;
+            if value <= 0.03928 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * channel(color.r()) + 0.7152 * channel(color.g()) + 0.0722 * channel(color.b())
+    }
+
+    fn contrast_ratio(first: Rgb888, second: Rgb888) -> f32 {
+        let (first, second) = (luminance(first), luminance(second));
+        (first.max(second) + 0.05) / (first.min(second) + 0.05)
+    }
+
+    #[test]
+    fn button_contrast_exceeds_aaa_level() {
+        let theme = apply::<Rgb888>();
+
+        for style in [
+            theme.button_style.idle,
+            theme.button_style.focus,
+            theme.button_style.active,
+            theme.button_style.drag,
+        ] {
+            let ratio = contrast_ratio(
+                style.foreground_color.unwrap(),
+                style.background_color.unwrap(),
+            );
+            assert!(ratio >= 7.0, "contrast ratio {ratio} is below 7:1");
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Circle, Line, PrimitiveStyle, Rectangle, Triangle},
+};
+
+use super::arc;
+
+/// Built-in vector symbols, drawn with primitives so they scale to any size without bitmaps
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symbol {
+    /// Filled triangle with an exclamation mark cut out
+    Warning,
+    /// Cogwheel with eight teeth
+    Gear,
+    ChevronUp,
+    ChevronDown,
+    ChevronLeft,
+    ChevronRight,
+}
+
+impl Symbol {
+    /// Draws the symbol centered in the largest square fitting `rect`. `background` is used for
+    /// cutouts (the exclamation mark and the gear hole), pass `None` to leave them transparent
+    pub fn draw<D, C>(&self, target: &mut D, rect: Rectangle, color: C, background: Option<C>)
+    where
+        D: DrawTarget<Color = C>,
+        C: PixelColor,
+    {
+        let side = rect.size.width.min(rect.size.height);
+        if side == 0 {
+            return;
+        }
+
+        let area = Rectangle::with_center(rect.center(), Size::new(side, side));
+        let top_left = area.top_left;
+        let last = side as i32 - 1;
+        let at = |x: i32, y: i32| top_left + Point::new(x, y);
+        let line_width = (side / 6).max(1);
+
+        match self {
+            Symbol::Warning => {
+                let _ = Triangle::new(at(last / 2, 0), at(last, last), at(0, last))
+                    .into_styled(PrimitiveStyle::with_fill(color))
+                    .draw(target);
+
+                if let Some(background) = background {
+                    let mark = PrimitiveStyle::with_stroke(background, line_width);
+                    let _ = Line::new(at(last / 2, last * 2 / 5), at(last / 2, last * 3 / 4 - 1))
+                        .into_styled(mark)
+                        .draw(target);
+                    let _ = Line::new(at(last / 2, last * 7 / 8), at(last / 2, last * 7 / 8))
+                        .into_styled(mark)
+                        .draw(target);
+                }
+            }
+            Symbol::Gear => {
+                let center = area.center();
+                let radius = side as f32 / 2.0;
+                let teeth = PrimitiveStyle::with_stroke(color, line_width);
+                for tooth in 0..8 {
+                    let outer = arc::polar_point(center, radius - 1.0, tooth as f32 * 45.0);
+                    let _ = Line::new(center, outer).into_styled(teeth).draw(target);
+                }
+
+                let _ = Circle::with_center(center, side * 3 / 4)
+                    .into_styled(PrimitiveStyle::with_fill(color))
+                    .draw(target);
+
+                if let Some(background) = background {
+                    let _ = Circle::with_center(center, side / 3)
+                        .into_styled(PrimitiveStyle::with_fill(background))
+                        .draw(target);
+                }
+            }
+            Symbol::ChevronUp => draw_chevron(target, area, ChevronDirection::Up, color),
+            Symbol::ChevronDown => draw_chevron(target, area, ChevronDirection::Down, color),
+            Symbol::ChevronLeft => draw_chevron(target, area, ChevronDirection::Left, color),
+            Symbol::ChevronRight => draw_chevron(target, area, ChevronDirection::Right, color),
+        }
+    }
+}
+
+/// Direction the chevron tip points to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChevronDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Returns the start, tip and end points of a chevron inscribed in `rect`
+fn chevron_points(rect: Rectangle, direction: ChevronDirection) -> [Point; 3] {
+    let side = rect.size.width.min(rect.size.height);
+    let area = Rectangle::with_center(rect.center(), Size::new(side, side));
+    let last = side as i32 - 1;
+    let inset = (side / 6).max(1) as i32;
+    let at = |x: i32, y: i32| area.top_left + Point::new(x, y);
+
+    match direction {
+        ChevronDirection::Up => [
+            at(inset, last * 3 / 4),
+            at(last / 2, last / 4),
+            at(last - inset, last * 3 / 4),
+        ],
+        ChevronDirection::Down => [
+            at(inset, last / 4),
+            at(last / 2, last * 3 / 4),
+            at(last - inset, last / 4),
+        ],
+        ChevronDirection::Left => [
+            at(last * 3 / 4, inset),
+            at(last / 4, last / 2),
+            at(last * 3 / 4, last - inset),
+        ],
+        ChevronDirection::Right => [
+            at(last / 4, inset),
+            at(last * 3 / 4, last / 2),
+            at(last / 4, last - inset),
+        ],
+    }
+}
+
+/// Draws a chevron (two lines meeting at the tip) centered in the largest square fitting `rect`.
+/// Used for dropdown, collapsible and submenu indicators
+pub fn draw_chevron<D, C>(target: &mut D, rect: Rectangle, direction: ChevronDirection, color: C)
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    if rect.is_zero_sized() {
+        return;
+    }
+
+    let [start, tip, end] = chevron_points(rect, direction);
+    let style =
+        PrimitiveStyle::with_stroke(color, (rect.size.width.min(rect.size.height) / 6).max(1));
+    let _ = Line::new(start, tip).into_styled(style).draw(target);
+    let _ = Line::new(tip, end).into_styled(style).draw(target);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    #[test]
+    fn down_chevron_apex_is_below_endpoints() {
+        let rect = Rectangle::new(Point::zero(), Size::new(12, 12));
+        let [start, tip, end] = chevron_points(rect, ChevronDirection::Down);
+        assert!(tip.y > start.y && tip.y > end.y);
+        assert!(start.x < tip.x && tip.x < end.x);
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        draw_chevron(&mut display, rect, ChevronDirection::Down, BinaryColor::On);
+        assert_eq!(display.get_pixel(tip), Some(BinaryColor::On));
+    }
+}
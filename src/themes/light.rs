@@ -0,0 +1,83 @@
+//! Light theme: dark text on a light background, for devices used in bright environments
+use embedded_graphics::{
+    pixelcolor::Rgb888,
+    prelude::{PixelColor, RgbColor, Size},
+};
+
+use crate::widgets::{plot::PlotStyle, slider::SliderStyle};
+
+use super::{ColorTheme, DynamicStyle, Theme, WidgetStyle};
+
+const LIGHT_COLORS: ColorTheme = ColorTheme {
+    background: Rgb888::new(244, 244, 240),
+    background2: Rgb888::new(222, 224, 226),
+    background3: Rgb888::new(200, 204, 208),
+    foreground: Rgb888::new(36, 40, 46),
+    foreground2: Rgb888::new(90, 98, 108),
+    foreground3: Rgb888::new(150, 156, 164),
+    accent: Rgb888::new(46, 125, 50),
+    success: Rgb888::new(46, 125, 50),
+    warning: Rgb888::new(176, 120, 0),
+    debug_rect: Rgb888::RED,
+};
+
+pub fn apply<C: PixelColor + From<Rgb888> + Default>() -> Theme<C> {
+    let colors = LIGHT_COLORS.into_color::<C>();
+    let button_style = WidgetStyle::default()
+        .background_color(colors.background2)
+        .foreground_color(colors.foreground)
+        .storke(1, colors.foreground3)
+        .accent_color(colors.accent);
+
+    let mut theme = Theme::from_colors(colors);
+    theme.button_style = DynamicStyle {
+        idle: button_style,
+        focus: button_style.storke(1, colors.foreground2),
+        active: button_style.background_color(colors.background3),
+        drag: button_style.background_color(colors.background3),
+    };
+    theme.slider_style =
+        SliderStyle::new(button_style.into(), button_style.into(), 1, Size::new(2, 5));
+    theme.gauge_style = theme.gauge_style.storke(2, colors.foreground2);
+    theme.modal_style = theme.modal_style.storke(2, colors.foreground3);
+    theme.progress_style = theme
+        .progress_style
+        .background_color(colors.background3)
+        .storke(3, colors.background3);
+    theme.plot_style = PlotStyle::new(
+        colors.background,
+        colors.background2,
+        colors.foreground3,
+        colors.foreground,
+    );
+    theme
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        widgets::{linear_layout::LinearLayoutBuilder, UiBuilder},
+        UiContext,
+    };
+    use embedded_graphics::{
+        mock_display::MockDisplay, mono_font::ascii::FONT_4X6, prelude::Point,
+    };
+
+    #[test]
+    fn button_background_is_light() {
+        let mut display = MockDisplay::<Rgb888>::new();
+        display.set_allow_overdraw(true);
+        let mut ctx = UiContext::new(display, apply());
+
+        let mut ui = LinearLayoutBuilder::default();
+        ui.button("OK", &FONT_4X6, || {});
+        ctx.update(ui.finish());
+
+        let background = ctx.draw_target.get_pixel(Point::new(3, 3)).unwrap();
+        assert!(background.r() > 200 && background.g() > 200 && background.b() > 200);
+
+        let text_color = ctx.theme.button_style.idle.foreground_color.unwrap();
+        assert!(text_color.r() < 64 && text_color.g() < 64 && text_color.b() < 64);
+    }
+}
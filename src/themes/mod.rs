@@ -1,6 +1,16 @@
-use embedded_graphics::{pixelcolor::Rgb888, prelude::PixelColor, primitives::PrimitiveStyle};
+use embedded_graphics::{
+    mono_font::{iso_8859_16::FONT_4X6, MonoFont},
+    pixelcolor::{raw::RawData, Rgb888},
+    prelude::{DrawTarget, PixelColor, Point, Primitive, RgbColor, Size, Transform},
+    primitives::{PrimitiveStyle, Rectangle},
+    Drawable,
+};
+use gradient::Gradient;
 
-use crate::{widgets::slider::SliderStyle, Event};
+use crate::{
+    widgets::{plot::PlotStyle, slider::SliderStyle, Modifier},
+    Event,
+};
 
 /// dynamic styles for widgets
 #[derive(Clone, Copy, Default)]
@@ -13,11 +23,18 @@ pub struct DynamicStyle<C: PixelColor> {
 
 impl<C: PixelColor> DynamicStyle<C> {
     pub fn style(&self, event: &Event) -> WidgetStyle<C> {
-        match event {
-            Event::Idle => self.idle,
-            Event::Focus => self.focus,
-            Event::Active(_) => self.active,
-            Event::Drag(_) => self.drag,
+        self.modifier_style(Modifier::from(event))
+    }
+
+    /// Returns the style for the [Modifier], see [crate::widgets::WidgetEvent::get_modifier].
+    /// [Modifier::Disabled] returns the idle style, [crate::UiContext::style] merges
+    /// [Theme::disabled_style] on top of it
+    pub fn modifier_style(&self, modifier: Modifier) -> WidgetStyle<C> {
+        match modifier {
+            Modifier::None | Modifier::Disabled => self.idle,
+            Modifier::Focus => self.focus,
+            Modifier::Active => self.active,
+            Modifier::Drag => self.drag,
         }
     }
 
@@ -25,53 +42,268 @@ impl<C: PixelColor> DynamicStyle<C> {
         self.idle
     }
 
+    /// Merges `style` on top of every state, see [WidgetStyle::merge]
+    pub fn merge(self, style: WidgetStyle<C>) -> Self {
+        Self {
+            idle: self.idle.merge(style),
+            focus: self.focus.merge(style),
+            active: self.active.merge(style),
+            drag: self.drag.merge(style),
+        }
+    }
+
     pub const fn new() -> Self {
         Self {
             active: WidgetStyle::new(),
             drag: WidgetStyle::new(),
             focus: WidgetStyle::new(),
-            idle: WidgetStyle::new()
+            idle: WidgetStyle::new(),
         }
     }
 }
 
+pub mod arc;
+pub mod caret;
+pub mod gradient;
+pub mod icons;
+pub mod high_contrast;
 pub mod hope_diamond;
+pub mod light;
+pub mod palette;
 
+/// Color set of a stock theme, see [Theme::from_colors]
 #[allow(dead_code)]
 #[derive(Clone, Copy)]
-pub(crate) struct ColorTheme {
+pub(crate) struct ColorTheme<C = Rgb888> {
     /// Primary background
-    pub(crate) background: Rgb888,
-    pub(crate) background2: Rgb888,
-    pub(crate) background3: Rgb888,
+    pub(crate) background: C,
+    pub(crate) background2: C,
+    pub(crate) background3: C,
     /// Primary foreground
-    pub(crate) foreground: Rgb888,
-    pub(crate) foreground2: Rgb888,
-    pub(crate) foreground3: Rgb888,
-    pub(crate) debug_rect: Rgb888,
-    pub(crate) success: Rgb888,
-    pub(crate) warning: Rgb888,
+    pub(crate) foreground: C,
+    pub(crate) foreground2: C,
+    pub(crate) foreground3: C,
+    /// Focus and active highlight
+    pub(crate) accent: C,
+    pub(crate) debug_rect: C,
+    pub(crate) success: C,
+    pub(crate) warning: C,
 }
 
-/// Theme struct. You can freely create own themes
+impl ColorTheme {
+    /// Converts the colors to the display color type
+    pub(crate) fn into_color<C: From<Rgb888>>(self) -> ColorTheme<C> {
+        ColorTheme {
+            background: self.background.into(),
+            background2: self.background2.into(),
+            background3: self.background3.into(),
+            foreground: self.foreground.into(),
+            foreground2: self.foreground2.into(),
+            foreground3: self.foreground3.into(),
+            accent: self.accent.into(),
+            debug_rect: self.debug_rect.into(),
+            success: self.success.into(),
+            warning: self.warning.into(),
+        }
+    }
+}
+
+/// Theme struct. You can freely create own themes: start from a stock theme (e.g.
+/// [hope_diamond::apply]) or [Theme::from_palette] and change its fields
 #[derive(Clone, Copy)]
 pub struct Theme<C: PixelColor> {
     pub button_style: DynamicStyle<C>,
     pub layout_style: DynamicStyle<C>,
     pub slider_style: SliderStyle<C>,
-    pub plot_style: WidgetStyle<C>,
+    pub plot_style: PlotStyle<C>,
     pub gauge_style: WidgetStyle<C>,
     pub modal_style: WidgetStyle<C>,
-    pub debug_rect: C,
+    /// Progress indicators style: background is the track, accent is the filled part
+    pub progress_style: WidgetStyle<C>,
+    /// Merged on top of the idle style of widgets inside a disabled layout, see
+    /// [crate::UiContext::style]
+    pub disabled_style: WidgetStyle<C>,
+    pub debug_style: DebugStyle<C>,
+    /// Screen background behind the layouts, see [crate::UiContext::background_color]
+    pub background_color: C,
     pub label_color: C,
+    /// Color of the animated focus indicator
+    pub focus_color: C,
+    /// Stroke width of the animated focus indicator
+    pub focus_width: u32,
+}
+
+/// Semantic color of a [Theme], see [Theme::color]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorRole {
+    Background,
+    Foreground,
+    Accent,
+    Secondary,
+    Surface,
+    Stroke,
+    Label,
+    Focus,
+}
+
+impl<C: PixelColor> Theme<C> {
+    /// Returns the theme color for `role`, for drawing with [embedded_graphics] directly in the
+    /// theme colors. Widget colors are taken from the base (idle) button style, the most complete
+    /// style of a theme. Returns `None` if the theme does not set the color
+    pub fn color(&self, role: ColorRole) -> Option<C> {
+        let base = self.button_style.base();
+        match role {
+            ColorRole::Background => base.background_color,
+            ColorRole::Foreground => base.foreground_color,
+            ColorRole::Accent => base.accent_color,
+            ColorRole::Secondary => base.secondary_color,
+            ColorRole::Surface => base.surface_color,
+            ColorRole::Stroke => base.stroke_color,
+            ColorRole::Label => Some(self.label_color),
+            ColorRole::Focus => Some(self.focus_color),
+        }
+    }
+}
+
+/// Style of the debug overlay, see [crate::UiContext::toggle_debug_mode]
+#[derive(Clone, Copy)]
+pub struct DebugStyle<C: PixelColor> {
+    /// Outline of every widget
+    pub debug_rect: C,
+    /// Outline of the focused widget, also the widget sizes text color
     pub debug_rect_active: C,
+    /// Outline of widgets drawing outside of their rect or nested too deep
+    pub debug_rect_overflow: C,
+    /// Widget ids text color
+    pub label_color: C,
+    pub font: &'static MonoFont<'static>,
+    /// Stroke width of the outlines
+    pub line_width: u32,
 }
 
-/// Base style for any widget, basically any widget can have this style
+impl<C: PixelColor> DebugStyle<C> {
+    /// Creates a debug style with the smallest font and 1 pixel outlines
+    pub const fn new(
+        debug_rect: C,
+        debug_rect_active: C,
+        debug_rect_overflow: C,
+        label_color: C,
+    ) -> Self {
+        Self {
+            debug_rect,
+            debug_rect_active,
+            debug_rect_overflow,
+            label_color,
+            font: &FONT_4X6,
+            line_width: 1,
+        }
+    }
+}
+
+/// Semantic colors to generate a [Theme] from, see [Theme::from_palette]
+#[derive(Clone, Copy)]
+pub struct ThemePalette<C: PixelColor> {
+    pub background: C,
+    pub foreground: C,
+    /// Focus and active highlight
+    pub accent: C,
+    /// Filled part of progress indicators
+    pub success: C,
+    /// Debug overflow outline
+    pub warning: C,
+}
+
+impl<C: PixelColor + From<Rgb888>> Theme<C> {
+    /// Shared base of the stock themes, each theme overrides what differs from it
+    pub(crate) fn from_colors(colors: ColorTheme<C>) -> Self {
+        let button_style = WidgetStyle::new()
+            .background_color(colors.background)
+            .foreground_color(colors.foreground)
+            .storke(2, colors.background2)
+            .accent_color(colors.accent);
+
+        Theme {
+            button_style: DynamicStyle {
+                idle: button_style,
+                focus: button_style.background_color(colors.background2),
+                active: button_style.background_color(colors.background3),
+                drag: button_style.background_color(colors.background2),
+            },
+            slider_style: SliderStyle::new(
+                button_style.into(),
+                button_style.into(),
+                1,
+                Size::new(2, 5),
+            ),
+            layout_style: DynamicStyle::new(),
+            debug_style: DebugStyle::new(
+                colors.debug_rect,
+                Rgb888::GREEN.into(),
+                colors.debug_rect,
+                colors.foreground,
+            ),
+            gauge_style: WidgetStyle::new()
+                .background_color(colors.background)
+                .foreground_color(colors.foreground)
+                .storke(2, colors.foreground),
+            modal_style: WidgetStyle::new()
+                .background_color(colors.background)
+                .foreground_color(colors.foreground)
+                .storke(2, colors.background2),
+            progress_style: WidgetStyle::new()
+                .background_color(colors.background2)
+                .foreground_color(colors.foreground)
+                .accent_color(colors.success)
+                .storke(3, colors.background2),
+            disabled_style: WidgetStyle::new().foreground_color(colors.foreground3),
+            plot_style: PlotStyle::new(
+                colors.background,
+                colors.background3,
+                colors.background2,
+                colors.foreground,
+            ),
+            background_color: colors.background,
+            label_color: colors.foreground,
+            focus_color: colors.accent,
+            focus_width: 1,
+        }
+    }
+}
+
+impl<C: RgbColor + From<Rgb888>> Theme<C> {
+    /// Generates a complete theme from a few semantic colors. Focused and pressed states use
+    /// background shades mixed with the foreground color
+    pub fn from_palette(palette: ThemePalette<C>) -> Self {
+        let muted = palette::blend(palette.background, palette.foreground, 0.5);
+        let mut theme = Self::from_colors(ColorTheme {
+            background: palette.background,
+            background2: palette::blend(palette.background, palette.foreground, 0.15),
+            background3: palette::blend(palette.background, palette.foreground, 0.3),
+            foreground: palette.foreground,
+            foreground2: muted,
+            foreground3: muted,
+            accent: palette.accent,
+            debug_rect: Rgb888::RED.into(),
+            success: palette.success,
+            warning: palette.warning,
+        });
+
+        theme.gauge_style = theme.gauge_style.accent_color(palette.accent);
+        theme.debug_style.debug_rect_overflow = palette.warning;
+        theme
+    }
+}
+
+/// Base style for any widget, basically any widget can have this style. Build it with
+/// [WidgetStyle::new] and the setters
 #[derive(Clone, Copy)]
 pub struct WidgetStyle<C: PixelColor> {
     /// Accent (active) color of widget
     pub accent_color: Option<C>,
+    /// Second emphasis level for widgets highlighting more than one element
+    pub secondary_color: Option<C>,
+    /// Color of raised areas inside of the widget, e.g. a dial face
+    pub surface_color: Option<C>,
     /// Foreground color for widget elements
     pub foreground_color: Option<C>,
     /// Background color for widget
@@ -80,16 +312,28 @@ pub struct WidgetStyle<C: PixelColor> {
     pub stroke_color: Option<C>,
     /// Border width
     pub stroke_width: u32,
+    /// Gradient drawn instead of the background color on color displays
+    pub background_gradient: Option<Gradient<C>>,
+    /// Per-side border drawn inside of the widget rect, in addition to the stroke
+    pub border: Option<BorderStyle<C>>,
+    /// Drop shadow as `(x offset, y offset, color)`, drawn behind the background on color
+    /// displays
+    pub shadow: Option<(i32, i32, C)>,
 }
 
 impl<C: PixelColor> Default for WidgetStyle<C> {
     fn default() -> Self {
         Self {
             accent_color: Default::default(),
+            secondary_color: None,
+            surface_color: None,
             foreground_color: Default::default(),
             background_color: Default::default(),
             stroke_color: Default::default(),
             stroke_width: Default::default(),
+            background_gradient: None,
+            border: None,
+            shadow: None,
         }
     }
 }
@@ -98,10 +342,15 @@ impl<C: PixelColor> WidgetStyle<C> {
     pub const fn new() -> Self {
         Self {
             accent_color: None,
+            secondary_color: None,
+            surface_color: None,
             background_color: None,
             foreground_color: None,
             stroke_color: None,
             stroke_width: 0,
+            background_gradient: None,
+            border: None,
+            shadow: None,
         }
     }
 
@@ -115,6 +364,16 @@ impl<C: PixelColor> WidgetStyle<C> {
         self
     }
 
+    pub const fn secondary_color(mut self, color: C) -> Self {
+        self.secondary_color = Some(color);
+        self
+    }
+
+    pub const fn surface_color(mut self, color: C) -> Self {
+        self.surface_color = Some(color);
+        self
+    }
+
     pub const fn background_color(mut self, color: C) -> Self {
         self.background_color = Some(color);
         self
@@ -125,6 +384,173 @@ impl<C: PixelColor> WidgetStyle<C> {
         self.stroke_width = width;
         self
     }
+
+    pub const fn background_gradient(mut self, gradient: Gradient<C>) -> Self {
+        self.background_gradient = Some(gradient);
+        self
+    }
+
+    /// Returns this style with every property set in `other` replaced by its value. Unset
+    /// (`None` or zero stroke width) properties of `other` keep the current value
+    pub fn merge(self, other: WidgetStyle<C>) -> Self {
+        Self {
+            accent_color: other.accent_color.or(self.accent_color),
+            secondary_color: other.secondary_color.or(self.secondary_color),
+            surface_color: other.surface_color.or(self.surface_color),
+            foreground_color: other.foreground_color.or(self.foreground_color),
+            background_color: other.background_color.or(self.background_color),
+            stroke_color: other.stroke_color.or(self.stroke_color),
+            stroke_width: if other.stroke_width > 0 {
+                other.stroke_width
+            } else {
+                self.stroke_width
+            },
+            background_gradient: other.background_gradient.or(self.background_gradient),
+            border: other.border.or(self.border),
+            shadow: other.shadow.or(self.shadow),
+        }
+    }
+
+    pub const fn border(mut self, border: BorderStyle<C>) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    pub const fn shadow(mut self, x: i32, y: i32, color: C) -> Self {
+        self.shadow = Some((x, y, color));
+        self
+    }
+
+    /// Draws the background and border. Gradient replaces the background color if the display
+    /// supports it
+    pub fn draw_background<D: DrawTarget<Color = C>>(&self, target: &mut D, rect: Rectangle) {
+        let mut style: PrimitiveStyle<C> = (*self).into();
+
+        if let Some((x, y, color)) = self.shadow {
+            if C::Raw::BITS_PER_PIXEL > 1 {
+                let _ = target.fill_solid(&rect.translate(Point::new(x, y)), color);
+            }
+        }
+
+        if let Some(gradient) = self.background_gradient {
+            if gradient.draw(target, rect) {
+                style.fill_color = None;
+            }
+        }
+
+        let _ = rect.into_styled(style).draw(target);
+
+        if let Some(border) = self.border {
+            border.draw(target, rect);
+        }
+    }
+}
+
+/// One side of a [BorderStyle]
+#[derive(Clone, Copy)]
+pub struct BorderSide<C: PixelColor> {
+    pub width: u32,
+    pub color: Option<C>,
+}
+
+impl<C: PixelColor> BorderSide<C> {
+    pub const fn none() -> Self {
+        Self {
+            width: 0,
+            color: None,
+        }
+    }
+
+    pub const fn new(width: u32, color: C) -> Self {
+        Self {
+            width,
+            color: Some(color),
+        }
+    }
+}
+
+/// Border with separate width and color for every side, e.g. a tab without the bottom border
+#[derive(Clone, Copy)]
+pub struct BorderStyle<C: PixelColor> {
+    pub top: BorderSide<C>,
+    pub right: BorderSide<C>,
+    pub bottom: BorderSide<C>,
+    pub left: BorderSide<C>,
+}
+
+impl<C: PixelColor> BorderStyle<C> {
+    /// Border without any sides
+    pub const fn new() -> Self {
+        Self {
+            top: BorderSide::none(),
+            right: BorderSide::none(),
+            bottom: BorderSide::none(),
+            left: BorderSide::none(),
+        }
+    }
+
+    /// Same border on every side
+    pub const fn all(width: u32, color: C) -> Self {
+        Self {
+            top: BorderSide::new(width, color),
+            right: BorderSide::new(width, color),
+            bottom: BorderSide::new(width, color),
+            left: BorderSide::new(width, color),
+        }
+    }
+
+    pub const fn top(mut self, width: u32, color: C) -> Self {
+        self.top = BorderSide::new(width, color);
+        self
+    }
+
+    pub const fn right(mut self, width: u32, color: C) -> Self {
+        self.right = BorderSide::new(width, color);
+        self
+    }
+
+    pub const fn bottom(mut self, width: u32, color: C) -> Self {
+        self.bottom = BorderSide::new(width, color);
+        self
+    }
+
+    pub const fn left(mut self, width: u32, color: C) -> Self {
+        self.left = BorderSide::new(width, color);
+        self
+    }
+
+    /// Draws the border inside of `rect`
+    pub fn draw<D: DrawTarget<Color = C>>(&self, target: &mut D, rect: Rectangle) {
+        let size = rect.size;
+        let sides = [
+            (self.top, rect.top_left, Size::new(size.width, self.top.width)),
+            (
+                self.bottom,
+                rect.top_left
+                    + Point::new(0, size.height.saturating_sub(self.bottom.width) as i32),
+                Size::new(size.width, self.bottom.width),
+            ),
+            (self.left, rect.top_left, Size::new(self.left.width, size.height)),
+            (
+                self.right,
+                rect.top_left + Point::new(size.width.saturating_sub(self.right.width) as i32, 0),
+                Size::new(self.right.width, size.height),
+            ),
+        ];
+
+        for (side, top_left, side_size) in sides {
+            if let Some(color) = side.color {
+                let side_rect = Rectangle::new(top_left, side_size).intersection(&rect);
+                let _ = target.fill_solid(&side_rect, color);
+            }
+        }
+    }
+}
+
+impl<C: PixelColor> Default for BorderStyle<C> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<C: PixelColor> Into<DynamicStyle<C>> for WidgetStyle<C> {
@@ -159,3 +585,30 @@ impl<C: PixelColor> From<PrimitiveStyle<C>> for WidgetStyle<C> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette_theme_uses_palette_colors() {
+        let theme = Theme::from_palette(ThemePalette {
+            background: Rgb888::new(10, 20, 30),
+            foreground: Rgb888::new(230, 230, 230),
+            accent: Rgb888::CYAN,
+            success: Rgb888::GREEN,
+            warning: Rgb888::YELLOW,
+        });
+
+        assert_eq!(
+            theme.button_style.idle.background_color,
+            Some(Rgb888::new(10, 20, 30))
+        );
+        assert_eq!(theme.label_color, Rgb888::new(230, 230, 230));
+        assert_eq!(theme.focus_color, Rgb888::CYAN);
+
+        let focus = theme.button_style.focus.background_color.unwrap();
+        let active = theme.button_style.active.background_color.unwrap();
+        assert!(10 < focus.r() && focus.r() < active.r() && active.r() < 230);
+    }
+}
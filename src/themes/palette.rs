@@ -0,0 +1,179 @@
+use alloc::vec::Vec;
+use embedded_graphics::{
+    pixelcolor::Rgb888,
+    prelude::{PixelColor, RgbColor},
+};
+
+/// Solid color band of a [Palette]: values in `range` (inclusive) take `color`
+#[derive(Copy, Clone)]
+pub struct PaletteBand<C: PixelColor> {
+    pub range: [f32; 2],
+    pub color: C,
+}
+
+impl<C: PixelColor> PaletteBand<C> {
+    pub fn new(range: [f32; 2], color: C) -> Self {
+        Self { range, color }
+    }
+
+    /// Checks if the value lies within the band range (inclusive)
+    pub fn contains(&self, value: f32) -> bool {
+        value >= self.range[0] && value <= self.range[1]
+    }
+}
+
+/// Value-to-color mapping (heatmaps, gauge detents, etc.) for normalized values. Linearly
+/// interpolates between color stops placed at positions in range 0.0-1.0, solid color bands
+/// (detents) take precedence over the gradient
+#[derive(Clone, Default)]
+pub struct Palette<C: PixelColor> {
+    stops: Vec<(f32, C)>,
+    bands: Vec<PaletteBand<C>>,
+}
+
+impl<C: PixelColor> Palette<C> {
+    pub fn new() -> Self {
+        Self {
+            stops: Vec::new(),
+            bands: Vec::new(),
+        }
+    }
+
+    /// Adds a solid color band. Overlapping bands resolve to the one added last, the one a
+    /// [crate::widgets::gauge::Gauge] draws on top
+    pub fn band(mut self, band: PaletteBand<C>) -> Self {
+        self.push_band(band);
+        self
+    }
+
+    pub(crate) fn push_band(&mut self, band: PaletteBand<C>) {
+        self.bands.push(band);
+    }
+
+    /// Returns the bands in the order they were added
+    pub fn bands(&self) -> &[PaletteBand<C>] {
+        &self.bands
+    }
+
+    /// Returns the color of the band containing `value` (see [Palette::band]), `None` outside of
+    /// the bands
+    pub fn band_color(&self, value: f32) -> Option<C> {
+        self.bands
+            .iter()
+            .rev()
+            .find(|band| band.contains(value))
+            .map(|band| band.color)
+    }
+
+    /// Adds a color stop at `position` (0.0-1.0)
+    pub fn stop(mut self, position: f32, color: C) -> Self {
+        let position = position.clamp(0.0, 1.0);
+        let index = self
+            .stops
+            .iter()
+            .position(|(stop, _)| *stop > position)
+            .unwrap_or(self.stops.len());
+        self.stops.insert(index, (position, color));
+        self
+    }
+}
+
+impl<C> Palette<C>
+where
+    C: RgbColor + From<Rgb888>,
+{
+    /// Returns a color for normalized value: the band color (see [Palette::band_color]), else
+    /// the gradient color, values outside of the stops take the nearest stop color. Returns
+    /// `None` if no band contains the value and the palette has no stops
+    pub fn color(&self, value: f32) -> Option<C> {
+        if let Some(color) = self.band_color(value) {
+            return Some(color);
+        }
+
+        let (first, last) = (self.stops.first()?, self.stops.last()?);
+
+        if value <= first.0 {
+            return Some(first.1);
+        }
+
+        if value >= last.0 {
+            return Some(last.1);
+        }
+
+        let (start, end) = self
+            .stops
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .find(|(_, end)| value <= end.0)?;
+
+        let span = end.0 - start.0;
+        if span <= 0.0 {
+            return Some(end.1);
+        }
+
+        Some(blend(start.1, end.1, (value - start.0) / span))
+    }
+}
+
+fn channel(value: u8, max: u8) -> f32 {
+    value as f32 * 255.0 / max as f32
+}
+
+fn lerp(from: f32, to: f32, t: f32) -> u8 {
+    (from + (to - from) * t + 0.5) as u8
+}
+
+pub(crate) fn blend<C: RgbColor + From<Rgb888>>(from: C, to: C, t: f32) -> C {
+    Rgb888::new(
+        lerp(channel(from.r(), C::MAX_R), channel(to.r(), C::MAX_R), t),
+        lerp(channel(from.g(), C::MAX_G), channel(to.g(), C::MAX_G), t),
+        lerp(channel(from.b(), C::MAX_B), channel(to.b(), C::MAX_B), t),
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::pixelcolor::Rgb565;
+
+    #[test]
+    fn midpoint_blend() {
+        let palette = Palette::new()
+            .stop(0.0, Rgb888::GREEN)
+            .stop(1.0, Rgb888::RED);
+
+        assert_eq!(palette.color(0.5), Some(Rgb888::new(128, 128, 0)));
+        assert_eq!(palette.color(0.0), Some(Rgb888::GREEN));
+        assert_eq!(palette.color(1.0), Some(Rgb888::RED));
+    }
+
+    #[test]
+    fn stops_are_sorted_and_clamped() {
+        let palette = Palette::new()
+            .stop(1.0, Rgb565::RED)
+            .stop(0.5, Rgb565::YELLOW)
+            .stop(0.0, Rgb565::GREEN);
+
+        assert_eq!(palette.color(-1.0), Some(Rgb565::GREEN));
+        assert_eq!(palette.color(0.5), Some(Rgb565::YELLOW));
+        assert_eq!(palette.color(2.0), Some(Rgb565::RED));
+        assert_eq!(Palette::<Rgb565>::new().color(0.5), None);
+    }
+
+    #[test]
+    fn last_band_wins_over_gradient() {
+        let palette = Palette::new()
+            .stop(0.0, Rgb888::BLACK)
+            .stop(1.0, Rgb888::WHITE)
+            .band(PaletteBand::new([0.0, 0.5], Rgb888::GREEN))
+            .band(PaletteBand::new([0.5, 0.8], Rgb888::RED));
+
+        assert_eq!(palette.color(0.2), Some(Rgb888::GREEN));
+        // the shared boundary belongs to the band added last
+        assert_eq!(palette.color(0.5), Some(Rgb888::RED));
+        assert_eq!(palette.band_color(0.5), Some(Rgb888::RED));
+        assert_eq!(palette.color(1.0), Some(Rgb888::WHITE));
+        assert_eq!(palette.band_color(1.0), None);
+    }
+}
@@ -49,7 +49,7 @@ where
     C: PixelColor + 'a,
 {
     fn size(&mut self, context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
-        self.max_size = context.draw_target.bounding_box().size;
+        self.max_size = context.viewport().size;
         self.layout.size(context, hint)
     }
 
@@ -1,23 +1,29 @@
 use alloc::{boxed::Box, string::String};
+use core::marker::PhantomData;
 use embedded_graphics::{
-    mono_font::{MonoFont, MonoTextStyle},
+    mono_font::{MonoFont, MonoTextStyle, MonoTextStyleBuilder},
     prelude::*,
     primitives::{PrimitiveStyle, Rectangle, StrokeAlignment},
     text::{renderer::TextRenderer, Alignment, Baseline, Text, TextStyleBuilder},
 };
 
-use crate::{themes::DynamicStyle, Event, EventResult, UiContext};
+use crate::{
+    text::TextMeasure,
+    themes::{DynamicStyle, WidgetStyle},
+    Event, EventResult, UiContext,
+};
 
 use super::{Widget, WidgetEvent};
 
-/// Generic button style and drawing implementation
+/// Generic button style and drawing implementation. The text is drawn with a [TextMeasure]
+/// style, a [MonoTextStyle] by default
 #[derive(Clone, Copy)]
-pub struct ButtonGeneric<'a, C: PixelColor> {
-    text_style: Option<MonoTextStyle<'a, C>>,
-    font: &'a MonoFont<'a>,
+pub struct ButtonGeneric<'a, C: PixelColor, S = MonoTextStyle<'a, C>> {
+    text_style: S,
     text_alignment: Alignment,
     pub padding: u32,
     pub style: DynamicStyle<C>,
+    font: PhantomData<&'a ()>,
 }
 
 impl<'a, C> ButtonGeneric<'a, C>
@@ -29,32 +35,52 @@ where
         text_alignment: Alignment,
         style: DynamicStyle<C>,
         padding: u32,
+    ) -> Self {
+        Self::new_with_text_style(
+            MonoTextStyleBuilder::new().font(font).build(),
+            text_alignment,
+            style,
+            padding,
+        )
+    }
+}
+
+impl<'a, C, S> ButtonGeneric<'a, C, S>
+where
+    C: PixelColor + 'a,
+    S: TextMeasure + TextRenderer<Color = C>,
+{
+    /// Creates a button style drawing the text with `text_style`, any [TextMeasure] text style.
+    /// The text color comes from `style`
+    pub fn new_with_text_style(
+        text_style: S,
+        text_alignment: Alignment,
+        style: DynamicStyle<C>,
+        padding: u32,
     ) -> Self {
         Self {
-            font,
+            text_style,
             style,
-            padding: padding,
+            padding,
             text_alignment,
-            text_style: None,
+            font: PhantomData,
         }
     }
 
-    pub fn size(&mut self, text: &str) -> Size {
+    pub fn size<D: DrawTarget<Color = C>>(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        text: &str,
+    ) -> Size {
         let base_style = self.style.style(&Event::Idle);
 
-        self.text_style = Some(MonoTextStyle::new(
-            self.font,
+        self.text_style.set_text_color(Some(
             base_style
                 .foreground_color
                 .expect("Button must have a foreground color for drawing"),
         ));
 
-        let text_size = self
-            .text_style
-            .unwrap()
-            .measure_string(text, Point::zero(), embedded_graphics::text::Baseline::Top)
-            .bounding_box
-            .size;
+        let text_size = context.measure_text(&self.text_style, text);
 
         Size::new(
             text_size.width + 2 * self.padding,
@@ -70,12 +96,19 @@ where
         text: &str,
     ) {
         const TEXT_BASELINE: Baseline = Baseline::Middle;
-        let mut converted_style: PrimitiveStyle<C> = self.style.style(event).into();
+        let style = context.style(&self.style, event);
+        let mut converted_style: PrimitiveStyle<C> = style.into();
         converted_style.stroke_alignment = StrokeAlignment::Inside;
         let styled_rect = rect.into_styled(converted_style);
         let _ = styled_rect.draw(&mut context.draw_target);
 
-        if let Some(style) = self.text_style {
+        // measured (see [ButtonGeneric::size]) buttons have a text color
+        if self.text_style.text_color().is_some() {
+            let mut text_style = self.text_style.clone();
+            if context.is_disabled() {
+                text_style.set_text_color(style.foreground_color.or(text_style.text_color()));
+            }
+            let style = text_style;
             let text = match self.text_alignment {
                 Alignment::Left => Text::with_baseline(
                     text,
@@ -90,7 +123,7 @@ where
                     Text::with_text_style(text, rect.center(), style, text_style.build())
                 }
                 Alignment::Right => {
-                    let text_width = text.len() as i32 * style.font.character_size.width as i32;
+                    let text_width = style.line_size(text).width as i32;
                     let x_pos =
                         rect.top_left.x + rect.size.width as i32 - text_width - self.padding as i32;
                     Text::with_baseline(
@@ -108,28 +141,44 @@ where
 }
 
 /// Button widget
-pub struct Button<'a, C: PixelColor> {
-    base: ButtonGeneric<'a, C>,
+pub struct Button<'a, C: PixelColor, S = MonoTextStyle<'a, C>> {
+    base: ButtonGeneric<'a, C, S>,
+    /// One-off style merged on top of the button style
+    style_override: Option<WidgetStyle<C>>,
     text: String,
     callback: Box<dyn FnMut() + 'a>,
 }
 
-impl<'a, C> Button<'a, C>
+impl<'a, C, S> Button<'a, C, S>
 where
     C: PixelColor + 'a,
+    S: TextMeasure + TextRenderer<Color = C>,
 {
     pub fn new_styled(
         text: String,
-        style: ButtonGeneric<'a, C>,
+        style: ButtonGeneric<'a, C, S>,
         callback: Box<dyn FnMut() + 'a>,
     ) -> Self {
         Self {
             base: style,
+            style_override: None,
             text,
             callback,
         }
     }
 
+    /// Overrides the set properties of `style` for this button only, in every state. The rest
+    /// comes from the theme
+    pub fn style(mut self, style: WidgetStyle<C>) -> Self {
+        self.style_override = Some(style);
+        self
+    }
+}
+
+impl<'a, C> Button<'a, C>
+where
+    C: PixelColor + 'a,
+{
     pub fn new(text: String, font: &'a MonoFont, callback: Box<dyn FnMut() + 'a>) -> Self {
         Self {
             base: ButtonGeneric::new(
@@ -143,16 +192,18 @@ where
                 },
                 6,
             ),
+            style_override: None,
             text,
             callback,
         }
     }
 }
 
-impl<'a, D, C> Widget<'a, D, C> for Button<'a, C>
+impl<'a, D, C, S> Widget<'a, D, C> for Button<'a, C, S>
 where
     D: DrawTarget<Color = C>,
     C: PixelColor + 'a,
+    S: TextMeasure + TextRenderer<Color = C> + 'a,
 {
     fn size(&mut self, context: &mut UiContext<'a, D, C>, _hint: Size) -> Size {
         let style = self.base.style.style(&Event::Idle);
@@ -160,7 +211,11 @@ where
             self.base.style = context.theme.button_style;
         }
 
-        self.base.size(&self.text)
+        if let Some(style_override) = self.style_override.take() {
+            self.base.style = self.base.style.merge(style_override);
+        }
+
+        self.base.size(context, &self.text)
     }
 
     fn is_interactive(&mut self) -> bool {
@@ -192,6 +247,9 @@ where
 mod tests {
     use crate::widgets::linear_layout::LinearLayoutBuilder;
     use crate::SystemEvent;
+    use alloc::boxed::Box;
+    use core::cell::Cell;
+    use crate::testing::{mock_context, render};
     use crate::{prelude::*, themes::hope_diamond, UiContext};
     use embedded_graphics::geometry::OriginDimensions;
     use embedded_graphics::mono_font::ascii::FONT_4X6;
@@ -199,6 +257,53 @@ mod tests {
     use embedded_graphics::primitives::Rectangle;
     use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb888};
 
+    #[test]
+    fn style_override_changes_only_one_button() {
+        let mut display = MockDisplay::<Rgb888>::new();
+        let disp_size = display.size();
+        display.set_allow_overdraw(true);
+        let mut ctx = UiContext::new(display, hope_diamond::apply());
+
+        let mut ui = LinearLayoutBuilder::default().direction(LayoutDirection::Vertical);
+        ui.add_widget(
+            Button::new("red".into(), &FONT_4X6, Box::new(|| {}))
+                .style(WidgetStyle::new().background_color(Rgb888::RED)),
+        );
+        ui.button("themed", &FONT_4X6, || {});
+        let mut ui = ui.finish();
+
+        ui.size(&mut ctx, disp_size);
+        ui.layout(&mut ctx, Rectangle::new(Point::zero(), disp_size));
+        ui.draw(&mut ctx, &SystemEvent::Idle);
+
+        // buttons are 18 pixels high: 6 pixels of text and 6 pixels of padding around
+        assert_eq!(ctx.draw_target.get_pixel(Point::new(3, 3)), Some(Rgb888::RED));
+        assert_eq!(
+            ctx.draw_target.get_pixel(Point::new(3, 21)),
+            ctx.theme.button_style.idle.background_color
+        );
+    }
+
+    #[test]
+    fn button_in_disabled_panel_does_not_fire() {
+        let pressed = Cell::new(false);
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.theme.disabled_style = ctx.theme.disabled_style.background_color(Rgb888::RED);
+
+        let mut panel = LinearLayoutBuilder::default().disabled(true);
+        panel.button("OK", &FONT_4X6, || pressed.set(true));
+        let mut ui = LinearLayoutBuilder::default();
+        ui.add_widget_obj(panel.finish());
+
+        let result = ctx.simulate(ui.finish(), SystemEvent::Active(Point::new(3, 3)));
+        assert_eq!(result, EventResult::Pass);
+        assert!(!pressed.get());
+        assert_eq!(
+            ctx.draw_target.get_pixel(Point::new(3, 3)),
+            Some(Rgb888::RED)
+        );
+    }
+
     #[test]
     fn button_render() {
         let mut display = MockDisplay::<Rgb888>::new();
@@ -223,4 +328,51 @@ mod tests {
             ctx.theme.button_style.idle.background_color
         );
     }
+
+    #[test]
+    fn button_has_border() {
+        let theme = hope_diamond::apply::<Rgb888>();
+        let style = theme.button_style.idle;
+        let stroke = style.stroke_color.unwrap();
+
+        let mut ui = LinearLayoutBuilder::default();
+        ui.button("OK", &FONT_4X6, || {});
+        let frame = render(ui.finish(), Size::new(24, 20), theme);
+
+        let dump = frame.ascii(|color| match color {
+            color if color == stroke => '#',
+            color if Some(color) == style.background_color => '.',
+            Rgb888::BLACK => ' ',
+            _ => 'x',
+        });
+
+        let expected: alloc::string::String = [
+            "####################    \n",
+            "####################    \n",
+            "##................##    \n",
+            "##................##    \n",
+            "##................##    \n",
+            "##................##    \n",
+            "##.....x..x.x.....##    \n",
+            "##....x.x.x.x.....##    \n",
+            "##....x.x.xx......##    \n",
+            "##....x.x.x.x.....##    \n",
+            "##.....x..x.x.....##    \n",
+            "##................##    \n",
+            "##................##    \n",
+            "##................##    \n",
+            "##................##    \n",
+            "##................##    \n",
+            "####################    \n",
+            "####################    \n",
+            "                        \n",
+            "                        \n",
+        ]
+        .concat();
+        assert_eq!(dump, expected);
+
+        // 2 pixel stroke on the 20x18 button
+        assert!(frame.is_filled(Rectangle::new(Point::zero(), Size::new(20, 2)), stroke));
+        assert!(frame.is_filled(Rectangle::new(Point::new(18, 0), Size::new(2, 18)), stroke));
+    }
 }
@@ -0,0 +1,129 @@
+use alloc::boxed::Box;
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+use crate::{EventResult, UiContext};
+
+use super::{to_coord, UiBuilder, Widget, WidgetEvent, WidgetObject};
+
+/// Takes all the available space and centers its only child on both axes, using the size the
+/// child reports. A child larger than the available space is clamped to it
+pub struct Center<'a, D, C>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    child: Option<WidgetObject<'a, D, C>>,
+}
+
+impl<'a, D, C> Center<'a, D, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    pub fn new() -> Self {
+        Self { child: None }
+    }
+}
+
+impl<'a, D, C> Default for Center<'a, D, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, D, C> UiBuilder<'a, D, C> for Center<'a, D, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn add_widget_obj(&mut self, widget: WidgetObject<'a, D, C>) {
+        if self.child.is_none() {
+            self.child = Some(widget);
+        } else {
+            panic!("Center already have a child!");
+        }
+    }
+
+    fn finish(self) -> WidgetObject<'a, D, C> {
+        if self.child.is_none() {
+            panic!("Center must have a child before finishing!");
+        }
+
+        WidgetObject::new(Box::new(self))
+    }
+}
+
+impl<'a, D, C> Widget<'a, D, C> for Center<'a, D, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn size(&mut self, context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
+        self.child.as_mut().unwrap().size(context, hint);
+        hint
+    }
+
+    fn layout(&mut self, context: &mut UiContext<'a, D, C>, rect: Rectangle) {
+        let child = self.child.as_mut().unwrap();
+        let child_size = child.size(context, rect.size).component_min(rect.size);
+        let free_space = rect.size - child_size;
+        let child_rect = Rectangle::new(
+            Point::new(
+                rect.top_left
+                    .x
+                    .saturating_add(to_coord(free_space.width / 2)),
+                rect.top_left
+                    .y
+                    .saturating_add(to_coord(free_space.height / 2)),
+            ),
+            child_size,
+        );
+
+        child.layout(context, child_rect);
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        _rect: Rectangle,
+        event_args: WidgetEvent,
+    ) -> EventResult {
+        self.child
+            .as_mut()
+            .unwrap()
+            .draw(context, event_args.system_event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{mock_context, Fixed},
+        widgets::linear_layout::LinearLayoutBuilder,
+    };
+    use core::cell::Cell;
+    use embedded_graphics::pixelcolor::Rgb888;
+
+    #[test]
+    fn small_child_is_centered() {
+        let placed = Cell::new(Rectangle::zero());
+        let mut ctx = mock_context::<Rgb888>();
+        let rect = Rectangle::new(Point::zero(), Size::new(100, 100));
+
+        let mut ui = LinearLayoutBuilder::default();
+        ui.center(|ui| ui.add_widget(Fixed(Size::new(10, 10), &placed)));
+        let mut ui = ui.finish();
+        ui.size(&mut ctx, rect.size);
+        ui.layout(&mut ctx, rect);
+
+        assert_eq!(
+            placed.get(),
+            Rectangle::new(Point::new(45, 45), Size::new(10, 10))
+        );
+    }
+}
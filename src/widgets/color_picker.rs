@@ -0,0 +1,193 @@
+use alloc::boxed::Box;
+use embedded_graphics::{
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+};
+
+use super::{Widget, WidgetEvent};
+use crate::{Event, EventResult, SystemEvent, UiContext};
+
+/// Grid of preset color swatches. Clicking a swatch (or activating the widget from keyboard
+/// after choosing a swatch with `Increase`/`Decrease`) reports its color
+pub struct ColorPicker<'a, C: RgbColor> {
+    swatches: &'a [C],
+    columns: usize,
+    /// Highlighted swatch index
+    pub selected: Option<usize>,
+    swatch_size: u32,
+    callback: Box<dyn FnMut(C) + 'a>,
+}
+
+impl<'a, C: RgbColor> ColorPicker<'a, C> {
+    pub fn new(swatches: &'a [C], columns: usize, callback: Box<dyn FnMut(C) + 'a>) -> Self {
+        Self {
+            swatches,
+            columns: columns.max(1),
+            selected: None,
+            swatch_size: 10,
+            callback,
+        }
+    }
+
+    /// Sets the side of one swatch, at least 1 pixel
+    pub fn swatch_size(mut self, swatch_size: u32) -> Self {
+        self.swatch_size = swatch_size.max(1);
+        self
+    }
+
+    fn rows(&self) -> usize {
+        self.swatches.len().div_ceil(self.columns)
+    }
+
+    fn swatch_rect(&self, rect: Rectangle, index: usize) -> Rectangle {
+        let column = (index % self.columns) as i32;
+        let row = (index / self.columns) as i32;
+        Rectangle::new(
+            rect.top_left
+                + Point::new(
+                    column * self.swatch_size as i32,
+                    row * self.swatch_size as i32,
+                ),
+            Size::new(self.swatch_size, self.swatch_size),
+        )
+    }
+
+    fn swatch_at(&self, position: Point) -> Option<usize> {
+        if position.x < 0 || position.y < 0 {
+            return None;
+        }
+
+        let column = position.x as usize / self.swatch_size as usize;
+        let row = position.y as usize / self.swatch_size as usize;
+        let index = row * self.columns + column;
+
+        (column < self.columns && index < self.swatches.len()).then_some(index)
+    }
+
+    fn pick(&mut self, index: usize) {
+        self.selected = Some(index);
+        (self.callback)(self.swatches[index]);
+    }
+}
+
+impl<'a, D, C> Widget<'a, D, C> for ColorPicker<'a, C>
+where
+    D: DrawTarget<Color = C>,
+    C: RgbColor + 'a,
+{
+    fn size(&mut self, _context: &mut UiContext<'a, D, C>, _hint: Size) -> Size {
+        Size::new(
+            self.columns as u32 * self.swatch_size,
+            self.rows() as u32 * self.swatch_size,
+        )
+    }
+
+    fn is_interactive(&mut self) -> bool {
+        true
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        event_args: WidgetEvent,
+    ) -> EventResult {
+        let mut event_result = match event_args.event {
+            Event::Focus => EventResult::Stop,
+            Event::Active(position) => {
+                context.focused_element = event_args.id;
+                let index = match position {
+                    Some(position) => self.swatch_at(*position),
+                    None => self.selected,
+                };
+
+                if let Some(index) = index {
+                    self.pick(index);
+                }
+                EventResult::Stop
+            }
+            _ => EventResult::Pass,
+        };
+
+        if event_args.is_focused && !self.swatches.is_empty() {
+            let last = self.swatches.len() - 1;
+            match event_args.system_event {
+                SystemEvent::Increase(_) => {
+                    self.selected = Some(self.selected.map_or(0, |index| (index + 1).min(last)));
+                    event_result = EventResult::Stop;
+                }
+                SystemEvent::Decrease(_) => {
+                    self.selected = Some(self.selected.map_or(0, |index| index.saturating_sub(1)));
+                    event_result = EventResult::Stop;
+                }
+                _ => {}
+            }
+        }
+
+        for (index, color) in self.swatches.iter().enumerate() {
+            let _ = self
+                .swatch_rect(rect, index)
+                .into_styled(PrimitiveStyle::with_fill(*color))
+                .draw(&mut context.draw_target);
+        }
+
+        if let Some(selected) = self.selected.filter(|&index| index < self.swatches.len()) {
+            let _ = self
+                .swatch_rect(rect, selected)
+                .into_styled(PrimitiveStyle::with_stroke(context.theme.focus_color, 1))
+                .draw(&mut context.draw_target);
+        }
+
+        event_result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mock_context;
+    use core::cell::Cell;
+    use embedded_graphics::pixelcolor::Rgb565;
+
+    #[test]
+    fn clicked_swatch_reports_color() {
+        let swatches = [Rgb565::RED, Rgb565::GREEN, Rgb565::BLUE, Rgb565::YELLOW];
+        let reported = Cell::new(None);
+        let mut ctx = mock_context::<Rgb565>();
+
+        let mut picker =
+            ColorPicker::new(&swatches, 2, Box::new(|color| reported.set(Some(color))));
+        let rect = Rectangle::new(Point::zero(), Size::new(20, 20));
+
+        picker.draw(
+            &mut ctx,
+            rect,
+            WidgetEvent {
+                event: &Event::Active(Some(Point::new(4, 15))),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(reported.get(), Some(Rgb565::BLUE));
+        assert_eq!(picker.selected, Some(2));
+    }
+
+    #[test]
+    fn zero_swatch_size_clamped() {
+        let swatches = [Rgb565::RED, Rgb565::GREEN];
+        let mut ctx = mock_context::<Rgb565>();
+
+        let mut picker = ColorPicker::new(&swatches, 2, Box::new(|_| {})).swatch_size(0);
+        assert_eq!(picker.size(&mut ctx, Size::new(64, 64)), Size::new(2, 1));
+
+        picker.draw(
+            &mut ctx,
+            Rectangle::new(Point::zero(), Size::new(2, 1)),
+            WidgetEvent {
+                event: &Event::Active(Some(Point::new(1, 0))),
+                ..Default::default()
+            },
+        );
+        assert_eq!(picker.selected, Some(1));
+    }
+}
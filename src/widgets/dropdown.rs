@@ -0,0 +1,340 @@
+use alloc::boxed::Box;
+use core::cell::Cell;
+use embedded_graphics::{
+    mono_font::MonoFont,
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle, StrokeAlignment},
+    text::Alignment,
+};
+
+use super::{
+    button::ButtonGeneric,
+    label::{Label, LabelOptions},
+    list::ListView,
+    Widget, WidgetEvent, WidgetObject,
+};
+use crate::{
+    themes::{
+        icons::{draw_chevron, ChevronDirection},
+        DynamicStyle,
+    },
+    Event, EventResult, SystemEvent, UiContext,
+};
+
+/// Persistent state of the [Dropdown], keep it outside of the UI tree. The popup list shares it
+/// with the dropdown, so the fields are [Cell]s
+#[derive(Debug, Default)]
+pub struct DropdownState {
+    open: Cell<bool>,
+    selected: Cell<usize>,
+}
+
+impl DropdownState {
+    pub fn new(selected: usize) -> Self {
+        Self {
+            open: Cell::new(false),
+            selected: Cell::new(selected),
+        }
+    }
+
+    /// Returns `true` while the option list is shown
+    pub fn is_open(&self) -> bool {
+        self.open.get()
+    }
+
+    /// Returns the index of the selected option
+    pub fn selected(&self) -> usize {
+        self.selected.get()
+    }
+}
+
+/// Popup of the [Dropdown], draws the list on the modal background. A press outside of the list
+/// or activating the dropdown again closes it
+struct Popup<'a, D, C>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    list: WidgetObject<'a, D, C>,
+    state: &'a DropdownState,
+    /// Id of the dropdown which opened the popup
+    owner: usize,
+}
+
+impl<'a, D, C> Widget<'a, D, C> for Popup<'a, D, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn layout(&mut self, context: &mut UiContext<'a, D, C>, rect: Rectangle) {
+        self.list.size(context, rect.size);
+        self.list.layout(context, rect);
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        event_args: WidgetEvent,
+    ) -> EventResult {
+        let mut style: PrimitiveStyle<C> = context.theme.modal_style.into();
+        style.stroke_alignment = StrokeAlignment::Inside;
+        let _ = rect.into_styled(style).draw(&mut context.draw_target);
+
+        match *event_args.system_event {
+            SystemEvent::Active(point) if !rect.contains(point) => {
+                self.state.open.set(false);
+                self.list.draw(context, &SystemEvent::Idle);
+                EventResult::Stop
+            }
+            SystemEvent::ActiveTo(id) if id == self.owner => {
+                self.state.open.set(false);
+                self.list.draw(context, &SystemEvent::Idle);
+                EventResult::Stop
+            }
+            _ => self.list.draw(context, event_args.system_event),
+        }
+    }
+}
+
+/// Returns the rect of a popup `height` pixels high under `header`, moved up and left to stay
+/// within `bounds`. A popup higher than `bounds` is cut to it
+fn popup_rect(header: Rectangle, height: u32, bounds: Rectangle) -> Rectangle {
+    let size = Size::new(
+        header.size.width.min(bounds.size.width),
+        height.min(bounds.size.height),
+    );
+    let bottom_right = bounds.top_left + bounds.size - size;
+    let top_left = Point::new(
+        header.top_left.x.min(bottom_right.x).max(bounds.top_left.x),
+        (header.top_left.y + header.size.height as i32)
+            .min(bottom_right.y)
+            .max(bounds.top_left.y),
+    );
+
+    Rectangle::new(top_left, size)
+}
+
+/// Shows the selected option, activating it opens the option list in an overlay (see
+/// [UiContext::push_overlay]). The list is modal, picking an option closes it and reports the
+/// option index
+pub struct Dropdown<'a, T: AsRef<str>, C: PixelColor> {
+    options: &'a [T],
+    state: &'a DropdownState,
+    font: &'a MonoFont<'a>,
+    base: ButtonGeneric<'a, C>,
+    callback: Option<Box<dyn FnMut(usize) + 'a>>,
+}
+
+impl<'a, T: AsRef<str>, C: PixelColor + 'a> Dropdown<'a, T, C> {
+    pub fn new(
+        options: &'a [T],
+        state: &'a DropdownState,
+        font: &'a MonoFont,
+        callback: Box<dyn FnMut(usize) + 'a>,
+    ) -> Self {
+        Self {
+            options,
+            state,
+            font,
+            base: ButtonGeneric::new(font, Alignment::Left, DynamicStyle::new(), 2),
+            callback: Some(callback),
+        }
+    }
+
+    fn selected_text(&self) -> &'a str {
+        self.options
+            .get(self.state.selected())
+            .map_or("", AsRef::as_ref)
+    }
+
+    fn row_height(&self) -> u32 {
+        self.font.character_size.height + 2
+    }
+}
+
+impl<'a, D, C, T> Widget<'a, D, C> for Dropdown<'a, T, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+    T: AsRef<str> + 'a,
+{
+    fn size(&mut self, context: &mut UiContext<'a, D, C>, _hint: Size) -> Size {
+        let style = self.base.style.style(&Event::Idle);
+        if style.foreground_color.is_none() && style.background_color.is_none() {
+            self.base.style = context.theme.button_style;
+        }
+
+        let size = self.base.size(context, self.selected_text());
+        // room for the chevron
+        size + Size::new(size.height, 0)
+    }
+
+    fn is_interactive(&mut self) -> bool {
+        true
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        event_args: WidgetEvent,
+    ) -> EventResult {
+        let event_result = match event_args.event {
+            Event::Focus => EventResult::Stop,
+            Event::Active(_) => {
+                context.focused_element = event_args.id;
+                self.state.open.set(!self.state.open.get());
+                EventResult::Stop
+            }
+            _ => EventResult::Pass,
+        };
+
+        self.base
+            .draw(context, rect, event_args.event, self.selected_text());
+
+        let chevron_side = rect.size.height;
+        let chevron_rect = Rectangle::new(
+            rect.top_left + Point::new(rect.size.width as i32 - chevron_side as i32, 0),
+            Size::new(chevron_side, chevron_side),
+        )
+        .offset(-(self.base.padding.min(chevron_side / 4) as i32));
+
+        if let Some(color) = context
+            .style(&self.base.style, event_args.event)
+            .foreground_color
+        {
+            let direction = if self.state.is_open() {
+                ChevronDirection::Up
+            } else {
+                ChevronDirection::Down
+            };
+            draw_chevron(&mut context.draw_target, chevron_rect, direction, color);
+        }
+
+        if self.state.is_open() {
+            if let Some(mut callback) = self.callback.take() {
+                let state = self.state;
+                let font = self.font;
+                let mut list = ListView::new(
+                    self.options,
+                    move |_, option: &T| {
+                        WidgetObject::new(Box::new(Label::new(
+                            option.as_ref(),
+                            LabelOptions::new(),
+                            font,
+                        )))
+                    },
+                    move |index| {
+                        state.selected.set(index);
+                        state.open.set(false);
+                        callback(index);
+                    },
+                );
+                list.row_height = Some(self.row_height());
+
+                let popup = Popup {
+                    list: WidgetObject::new(Box::new(list)),
+                    state,
+                    owner: event_args.id,
+                };
+                let popup_rect = popup_rect(
+                    rect,
+                    self.row_height() * self.options.len() as u32,
+                    context.viewport(),
+                );
+                context.push_overlay(popup_rect, WidgetObject::new(Box::new(popup)));
+            }
+        }
+
+        event_result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::mock_context,
+        widgets::{linear_layout::LinearLayoutBuilder, UiBuilder},
+    };
+    use embedded_graphics::{mono_font::ascii::FONT_4X6, pixelcolor::Rgb888};
+
+    #[test]
+    fn picking_second_option_reports_index_and_closes() {
+        let options = ["Low", "High", "Auto"];
+        let state = DropdownState::new(0);
+        let picked = Cell::new(None);
+        let mut ctx = mock_context::<Rgb888>();
+
+        let ui = || {
+            let mut ui = LinearLayoutBuilder::default();
+            ui.add_widget(Dropdown::new(
+                &options,
+                &state,
+                &FONT_4X6,
+                Box::new(|index| picked.set(Some(index))),
+            ));
+            ui.finish()
+        };
+
+        ctx.simulate(ui(), SystemEvent::Active(Point::new(2, 2)));
+        assert!(state.is_open());
+        assert_eq!(picked.get(), None);
+
+        // header is 10px high, rows are 8px high
+        let result = ctx.simulate(ui(), SystemEvent::Active(Point::new(2, 10 + 8 + 3)));
+        assert_eq!(result, EventResult::Stop);
+        assert_eq!(picked.get(), Some(1));
+        assert_eq!(state.selected(), 1);
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn press_outside_or_on_header_closes_popup() {
+        let options = ["Low", "High", "Auto"];
+        let state = DropdownState::new(0);
+        let picked = Cell::new(None);
+        let mut ctx = mock_context::<Rgb888>();
+
+        let ui = || {
+            let mut ui = LinearLayoutBuilder::default();
+            ui.add_widget(Dropdown::new(
+                &options,
+                &state,
+                &FONT_4X6,
+                Box::new(|index| picked.set(Some(index))),
+            ));
+            ui.finish()
+        };
+
+        for press in [Point::new(60, 60), Point::new(2, 2)] {
+            ctx.simulate(ui(), SystemEvent::Active(Point::new(2, 2)));
+            assert!(state.is_open());
+
+            let result = ctx.simulate(ui(), SystemEvent::Active(press));
+            assert_eq!(result, EventResult::Stop);
+            assert!(!state.is_open(), "press at {press}");
+            ctx.update(ui());
+            assert!(!state.is_open(), "press at {press}");
+        }
+        assert_eq!(picked.get(), None);
+    }
+
+    #[test]
+    fn popup_stays_on_screen() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(64, 64));
+        let header = Rectangle::new(Point::new(40, 50), Size::new(30, 10));
+
+        // moved above the bottom edge and left of the right edge
+        assert_eq!(
+            popup_rect(header, 24, bounds),
+            Rectangle::new(Point::new(34, 40), Size::new(30, 24))
+        );
+        // cut to the screen height
+        assert_eq!(
+            popup_rect(header, 100, bounds),
+            Rectangle::new(Point::new(34, 0), Size::new(30, 64))
+        );
+    }
+}
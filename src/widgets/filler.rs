@@ -3,13 +3,16 @@ use crate::UiContext;
 use super::Widget;
 use embedded_graphics::prelude::*;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FillStrategy {
     Vertical,
     Horizontal,
     Both,
 }
 
-/// Widget space filler widget
+/// Widget space filler widget. In a [super::linear_layout::LinearLayout] fillers share the free
+/// space on the layout axis before the alignment applies, so a filler between two widgets pushes
+/// them to the opposite ends
 pub struct Filler {
     fill: FillStrategy,
 }
@@ -32,4 +35,8 @@ where
             FillStrategy::Both => hint,
         }
     }
+
+    fn fill_strategy(&mut self) -> Option<FillStrategy> {
+        Some(self.fill)
+    }
 }
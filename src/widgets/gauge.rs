@@ -3,18 +3,46 @@
 use core::f32::consts::PI;
 use micromath::F32Ext;
 
-use super::{Widget, WidgetEvent};
-use crate::{EventResult, UiContext};
-use alloc::{string::ToString, vec::Vec};
+use super::{finite_value, Widget, WidgetEvent};
+use crate::{
+    easing,
+    themes::{
+        arc,
+        palette::{Palette, PaletteBand},
+    },
+    EventResult, UiContext,
+};
+use alloc::{format, string::{String, ToString}, vec::Vec};
 use embedded_graphics::{
     mono_font::{ascii::FONT_4X6, MonoTextStyle},
     prelude::*,
-    primitives::{
-        Arc, Circle, Line, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, StyledDrawable,
-    },
-    text::{Alignment, Text},
+    primitives::{Circle, Line, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, StyledDrawable},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
 };
 
+/// Distance between the tick ring and the center of a division label
+const LABEL_OFFSET: f32 = 7.0;
+
+/// Where the division labels are placed, see [GaugeStyle::display_values]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum GaugeLabelPlacement {
+    /// Inside of the tick ring
+    #[default]
+    Inside,
+    /// Outside of the tick ring, the dial shrinks to make room for the labels
+    Outside,
+}
+
+/// How the division labels are oriented, see [GaugeStyle::display_values]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum GaugeLabelOrientation {
+    /// Labels are always drawn horizontally
+    #[default]
+    Upright,
+    /// Labels are rotated with the dial, their tops point outwards
+    Rotated,
+}
+
 #[derive(Copy, Clone)]
 pub struct GaugeDetent<C: PixelColor> {
     pub range: [f32; 2],
@@ -25,22 +53,69 @@ impl<C: PixelColor> GaugeDetent<C> {
     pub fn new(range: [f32; 2], color: C) -> Self {
         Self { range, color }
     }
+
+    /// Checks if the value lies within the detent range (inclusive)
+    pub fn contains(&self, value: f32) -> bool {
+        value >= self.range[0] && value <= self.range[1]
+    }
+}
+
+impl<C: PixelColor> From<GaugeDetent<C>> for PaletteBand<C> {
+    fn from(detent: GaugeDetent<C>) -> Self {
+        PaletteBand::new(detent.range, detent.color)
+    }
 }
 
 #[derive(Copy, Clone)]
 pub struct GaugeStyle {
     divisions: u32,
     display_values: bool,
+    label_placement: GaugeLabelPlacement,
+    label_orientation: GaugeLabelOrientation,
     min_angle: f32,
     max_angle: f32,
+    flash_period: u32,
+    center_zero: bool,
 }
 
 impl GaugeStyle {
+    /// Gauge which starts at `start` degrees and spans `sweep` degrees clockwise. Angles are
+    /// measured from the bottom of the dial, sweep is limited to a full turn
+    pub fn sweep(start: f32, sweep: f32) -> Self {
+        Self {
+            min_angle: start,
+            max_angle: start + sweep.clamp(0.0, 360.0),
+            ..Default::default()
+        }
+    }
+
+    /// Full-circle gauge starting (and ending) at the top of the dial
+    pub fn full_circle() -> Self {
+        Self::sweep(180.0, 360.0)
+    }
+
     pub fn divisions(mut self, divisions: u32) -> Self {
         self.divisions = divisions.clamp(2, u32::MAX);
         self
     }
 
+    /// Draws a value label at every division. The values come from the readout range (see
+    /// [Gauge::readout]), or are 0.0-1.0 without a readout
+    pub fn display_values(mut self, display_values: bool) -> Self {
+        self.display_values = display_values;
+        self
+    }
+
+    pub fn label_placement(mut self, placement: GaugeLabelPlacement) -> Self {
+        self.label_placement = placement;
+        self
+    }
+
+    pub fn label_orientation(mut self, orientation: GaugeLabelOrientation) -> Self {
+        self.label_orientation = orientation;
+        self
+    }
+
     pub fn min_angle(mut self, min_angle: f32) -> Self {
         self.min_angle = min_angle;
         self
@@ -50,6 +125,72 @@ impl GaugeStyle {
         self.max_angle = max_angle;
         self
     }
+
+    /// Center-zero (bidirectional) gauge, e.g. vertical speed or trim: values are in range
+    /// -1.0-1.0 and 0.0 points to the middle of the sweep (straight up for symmetric sweeps).
+    /// The needle of a negative value uses the secondary color
+    pub fn center_zero(mut self, center_zero: bool) -> Self {
+        self.center_zero = center_zero;
+        self
+    }
+
+    /// Sets how many frames the redline flash stays in one phase
+    pub fn flash_period(mut self, frames: u32) -> Self {
+        self.flash_period = frames.clamp(1, u32::MAX);
+        self
+    }
+
+    fn span(&self) -> f32 {
+        (self.max_angle - self.min_angle).clamp(0.0, 360.0)
+    }
+
+    fn is_full_circle(&self) -> bool {
+        self.span() >= 360.0
+    }
+
+    /// Maps a value in range 0.0-1.0 (-1.0-1.0 for [GaugeStyle::center_zero]) to the dial angle in degrees, values outside of range are clamped to the configured span
+    pub fn value_angle(&self, value: f32) -> f32 {
+        self.min_angle + self.span() * self.dial_fraction(value)
+    }
+
+    /// Maps a value to the fraction (0.0-1.0) of the sweep
+    fn dial_fraction(&self, value: f32) -> f32 {
+        if self.center_zero {
+            (value.clamp(-1.0, 1.0) + 1.0) / 2.0
+        } else {
+            value.clamp(0.0, 1.0)
+        }
+    }
+
+    /// Sanitizes a value like [finite_value], infinities are clamped to the value range of the
+    /// style (-1.0-1.0 for [GaugeStyle::center_zero])
+    fn finite_value(&self, value: f32) -> f32 {
+        if self.center_zero && value.is_infinite() {
+            value.clamp(-1.0, 1.0)
+        } else {
+            finite_value(value)
+        }
+    }
+
+    /// Maps a fraction (0.0-1.0) of the sweep to the value, reverse of [Self::dial_fraction]
+    fn fraction_value(&self, fraction: f32) -> f32 {
+        if self.center_zero {
+            fraction * 2.0 - 1.0
+        } else {
+            fraction
+        }
+    }
+
+    /// Returns the center of the division label at `angle` (screen angle in degrees, see
+    /// [arc]) for a dial with `radius` and ticks of `tick_length`
+    fn label_center(&self, center: Point, radius: f32, tick_length: f32, angle: f32) -> Point {
+        let distance = match self.label_placement {
+            GaugeLabelPlacement::Inside => radius - tick_length - LABEL_OFFSET,
+            GaugeLabelPlacement::Outside => radius + LABEL_OFFSET,
+        };
+
+        arc::polar_point(center, distance, angle)
+    }
 }
 
 impl Default for GaugeStyle {
@@ -59,30 +200,166 @@ impl Default for GaugeStyle {
             min_angle: 40.0,
             max_angle: 320.0,
             display_values: false,
+            label_placement: GaugeLabelPlacement::Inside,
+            label_orientation: GaugeLabelOrientation::Upright,
+            flash_period: 8,
+            center_zero: false,
         }
     }
 }
 
+/// Numeric value readout drawn under the gauge label
+#[derive(Copy, Clone)]
+pub struct GaugeReadout<'a> {
+    /// Values shown for 0.0 and 1.0 gauge positions
+    pub range: [f32; 2],
+    /// Digits after the decimal point
+    pub decimals: usize,
+    /// Unit suffix, e.g `"RPM"` or `"%"`
+    pub unit: &'a str,
+}
+
 /// Gauge widget
 pub struct Gauge<'a, C: PixelColor> {
     pub value: f32,
-    detents: Vec<GaugeDetent<C>>,
+    /// When `value` exceeds this threshold the needle and dial outline flash, using the color of the detent under the needle
+    pub redline: Option<f32>,
+    detents: Palette<C>,
     gauge_style: GaugeStyle,
+    readout: Option<GaugeReadout<'a>>,
     text: &'a str,
+    /// Color blending for the anti-aliased detents and needle, `None` draws them aliased
+    #[cfg(feature = "antialiasing")]
+    antialias: Option<crate::antialias::Blend<C>>,
 }
 
 impl<'a, C: PixelColor> Gauge<'a, C> {
     pub fn new(value: f32, text: &'a str, gauge_style: GaugeStyle) -> Self {
         Self {
-            value,
+            value: gauge_style.finite_value(value),
+            redline: None,
             gauge_style,
-            detents: Vec::new(),
+            detents: Palette::new(),
+            readout: None,
             text,
+            #[cfg(feature = "antialiasing")]
+            antialias: None,
         }
     }
 
+    /// Shows the value mapped to `range` with a `unit` suffix under the label
+    pub fn readout(&mut self, range: [f32; 2], decimals: usize, unit: &'a str) {
+        self.readout = Some(GaugeReadout {
+            range,
+            decimals,
+            unit,
+        });
+    }
+
+    fn readout_text(&self) -> Option<String> {
+        let readout = self.readout?;
+        let value = readout.range[0] + (readout.range[1] - readout.range[0]) * self.value;
+        Some(format!("{:.*}{}", readout.decimals, value, readout.unit))
+    }
+
+    /// Returns the division label for the `fraction` (0.0-1.0) of the dial
+    fn division_label(&self, fraction: f32) -> String {
+        let value = self.gauge_style.fraction_value(fraction);
+        match self.readout {
+            Some(readout) => {
+                let value = readout.range[0] + (readout.range[1] - readout.range[0]) * value;
+                format!("{:.*}", readout.decimals, value)
+            }
+            None => format!("{:.1}", value),
+        }
+    }
+
+    /// Self-test sweep on first display: within `frames` frames the needle sweeps from 0.0 to
+    /// full scale and then settles on the value. Keeps no state, `frame` counts from the first
+    /// display, e.g. [UiContext::frame_counter] for a sweep on startup. Later frames show the
+    /// value as is
+    pub fn startup_sweep(&mut self, frame: u32, frames: u32) {
+        if frame >= frames {
+            return;
+        }
+
+        let half = (frames / 2).max(1);
+        self.value = if frame < half {
+            easing::interpolate(0.0, 1.0, frame as f32 / half as f32, easing::ease_in_out)
+        } else {
+            let progress = (frame - half) as f32 / (frames - half) as f32;
+            easing::interpolate(
+                1.0,
+                self.gauge_style.finite_value(self.value),
+                progress,
+                easing::ease_out,
+            )
+        };
+    }
+
+    /// Adds a colored range to the dial. Detents are drawn in the order they were added, the
+    /// redline flash takes the color of the last one under the needle (see [Palette::band])
     pub fn add_detent(&mut self, detent: GaugeDetent<C>) {
-        self.detents.push(detent);
+        self.detents.push_band(detent.into());
+    }
+
+    fn redline_color(&self, frame: u32, fallback: C) -> Option<C> {
+        let redline = self.redline?;
+        let flash_on = (frame / self.gauge_style.flash_period) & 1 == 0;
+
+        if self.value <= redline || !flash_on {
+            return None;
+        }
+
+        Some(self.detents.band_color(self.value).unwrap_or(fallback))
+    }
+}
+
+#[cfg(feature = "antialiasing")]
+impl<'a, C> Gauge<'a, C>
+where
+    C: RgbColor + From<embedded_graphics::pixelcolor::Rgb888>,
+{
+    /// Draws the detents and the needle anti-aliased (supersampled), blending the edges with the
+    /// dial face color. Noticeably slower, meant for larger color displays
+    pub fn set_antialiased(&mut self, enabled: bool) {
+        self.antialias = enabled.then_some(crate::themes::palette::blend::<C>);
+    }
+}
+
+/// Rotates the drawn pixels around `origin`, for division labels rotated with the dial
+struct RotatedLabel<'t, D> {
+    target: &'t mut D,
+    origin: Point,
+    cos: f32,
+    sin: f32,
+}
+
+impl<D: DrawTarget> Dimensions for RotatedLabel<'_, D> {
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for RotatedLabel<'_, D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let (origin, cos, sin) = (self.origin, self.cos, self.sin);
+        self.target
+            .draw_iter(pixels.into_iter().map(|Pixel(point, color)| {
+                let offset = point - origin;
+                let (x, y) = (offset.x as f32, offset.y as f32);
+                let rotated = Point::new(
+                    (x * cos - y * sin).round() as i32,
+                    (x * sin + y * cos).round() as i32,
+                );
+                Pixel(origin + rotated, color)
+            }))
     }
 }
 
@@ -101,85 +378,163 @@ where
         rect: Rectangle,
         _event_args: WidgetEvent,
     ) -> EventResult {
+        // `value` is public, it may have been set after construction
+        self.value = self.gauge_style.finite_value(self.value);
         let style = context.theme.gauge_style;
         let foreground_color = style
             .foreground_color
             .expect("Gauge must have a foreground color to draw");
         let stroke_color = style.stroke_color.unwrap_or(foreground_color);
         let accent_color = style.accent_color.unwrap_or(foreground_color);
+        let secondary_color = style.secondary_color.unwrap_or(accent_color);
 
         let gauge_stroke_width = style.stroke_width.clamp(2, u32::MAX);
+        let flash_color = self.redline_color(
+            context.frame_counter(),
+            style.background_color.unwrap_or(foreground_color),
+        );
+
+        let mut circle_style: PrimitiveStyle<C> = style.into();
+        if style.surface_color.is_some() {
+            circle_style.fill_color = style.surface_color;
+        }
+
+        if flash_color.is_some() {
+            circle_style.stroke_color = flash_color;
+        }
+
+        let label_room = match self.gauge_style.label_placement {
+            GaugeLabelPlacement::Outside if self.gauge_style.display_values => {
+                4 * LABEL_OFFSET as u32
+            }
+            _ => 0,
+        };
 
         let circle = Circle::with_center(
             Point::new(rect.center().x, rect.center().y),
-            rect.size.width - gauge_stroke_width,
+            rect.size
+                .width
+                .saturating_sub(gauge_stroke_width)
+                .saturating_sub(label_room),
         )
-        .into_styled(style.into());
+        .into_styled(circle_style);
 
         let circle_size = circle.primitive.diameter;
         let center = circle.primitive.center();
         let _ = circle.draw(&mut context.draw_target);
 
+        // anti-aliased edges blend with the dial face
+        #[cfg(feature = "antialiasing")]
+        let smooth = self.antialias.map(|blend| {
+            let face = circle_style
+                .fill_color
+                .or(style.background_color)
+                .unwrap_or(foreground_color);
+            (blend, face)
+        });
+
         // draw detents
-        for detent in self.detents.iter() {
-            let angle_start = self.gauge_style.min_angle
-                + (self.gauge_style.max_angle - self.gauge_style.min_angle) * detent.range[0];
-            let angle_end = self.gauge_style.min_angle
-                + (self.gauge_style.max_angle - self.gauge_style.min_angle) * detent.range[1];
+        for detent in self.detents.bands() {
+            let angle_start = self.gauge_style.value_angle(detent.range[0]);
+            let angle_end = self.gauge_style.value_angle(detent.range[1]);
             let angle_sweep = angle_end - angle_start;
-            let arc = Arc::from_circle(
-                circle.primitive,
-                Angle::from_degrees(angle_start + 90.0),
-                Angle::from_degrees(angle_sweep),
-            )
-            .into_styled(PrimitiveStyle::with_stroke(
-                detent.color,
-                gauge_stroke_width / 2,
-            ));
 
-            let _ = arc.draw(&mut context.draw_target);
+            #[cfg(feature = "antialiasing")]
+            if let Some((blend, face)) = smooth {
+                crate::antialias::draw_arc(
+                    &mut context.draw_target,
+                    circle.primitive,
+                    angle_start + 90.0,
+                    angle_sweep,
+                    gauge_stroke_width / 2,
+                    detent.color,
+                    face,
+                    blend,
+                );
+                continue;
+            }
+
+            arc::draw_arc(
+                &mut context.draw_target,
+                center,
+                circle_size,
+                angle_start + 90.0,
+                angle_sweep,
+                PrimitiveStyle::with_stroke(detent.color, gauge_stroke_width / 2),
+            );
+
+            // round caps, so adjacent detents join without a gap
+            for angle in [angle_start, angle_end] {
+                arc::draw_round_cap(
+                    &mut context.draw_target,
+                    center,
+                    circle_size,
+                    angle + 90.0,
+                    gauge_stroke_width / 2,
+                    detent.color,
+                );
+            }
         }
 
         // draw a dashes
-        let total_angle = self.gauge_style.max_angle - self.gauge_style.min_angle;
-        let angle_step = total_angle / (self.gauge_style.divisions - 1) as f32;
+        // on a full circle the last division would overlap the first one
+        let total_angle = self.gauge_style.span();
+        let angle_step = if self.gauge_style.is_full_circle() {
+            total_angle / self.gauge_style.divisions as f32
+        } else {
+            total_angle / (self.gauge_style.divisions - 1) as f32
+        };
 
         let tick_length = circle_size as f32 * 0.1;
         let line_width = gauge_stroke_width as f32 / 2.0;
 
         for i in 0..self.gauge_style.divisions {
-            let angle = (self.gauge_style.min_angle + i as f32 * angle_step) + 90.0;
-            let angle_rad = angle.to_radians();
-
-            let start_x =
-                center.x as f32 + (circle_size as f32 / 2.0 - line_width / 2.0) * angle_rad.cos();
-            let start_y =
-                center.y as f32 + (circle_size as f32 / 2.0 - line_width / 2.0) * angle_rad.sin();
-
-            let end_x =
-                center.x as f32 + (circle_size as f32 / 2.0 - tick_length) * angle_rad.cos();
-            let end_y =
-                center.y as f32 + (circle_size as f32 / 2.0 - tick_length) * angle_rad.sin();
+            let angle = (self.gauge_style.min_angle + i as f32 * angle_step) % 360.0 + 90.0;
+            let (start, end) = arc::tick_endpoints(
+                center,
+                circle_size as f32 / 2.0 - line_width / 2.0,
+                tick_length - line_width / 2.0,
+                angle,
+            );
 
             if self.gauge_style.display_values {
-                let tex_end_x =
-                    center.x as f32 + (circle_size as f32 / 2.5 - tick_length) * angle_rad.cos();
-                let tex_end_y =
-                    center.x as f32 + (circle_size as f32 / 2.5 - tick_length) * angle_rad.sin();
-
-                let _ = Text::new(
-                    "0",
-                    Point::new(tex_end_x as i32, tex_end_y as i32),
-                    MonoTextStyle::new(&FONT_4X6, stroke_color),
-                )
-                .draw(&mut context.draw_target);
+                let fraction = (i as f32 * angle_step / total_angle.max(1.0)).min(1.0);
+                let label = self.division_label(fraction);
+                let position = self.gauge_style.label_center(
+                    center,
+                    circle_size as f32 / 2.0,
+                    tick_length,
+                    angle,
+                );
+                let text_style = TextStyleBuilder::new()
+                    .alignment(Alignment::Center)
+                    .baseline(Baseline::Middle)
+                    .build();
+                let character_style = MonoTextStyle::new(&FONT_4X6, stroke_color);
+
+                match self.gauge_style.label_orientation {
+                    GaugeLabelOrientation::Upright => {
+                        let _ =
+                            Text::with_text_style(&label, position, character_style, text_style)
+                                .draw(&mut context.draw_target);
+                    }
+                    GaugeLabelOrientation::Rotated => {
+                        // tops point outwards: rotated a quarter turn past the tick angle
+                        let angle_rad = angle.to_radians();
+                        let mut target = RotatedLabel {
+                            target: &mut context.draw_target,
+                            origin: position,
+                            cos: (angle_rad + PI / 2.0).cos(),
+                            sin: (angle_rad + PI / 2.0).sin(),
+                        };
+                        let _ =
+                            Text::with_text_style(&label, position, character_style, text_style)
+                                .draw(&mut target);
+                    }
+                }
             }
 
-            let _ = Line::new(
-                Point::new((start_x + 0.5) as i32, (start_y + 0.5) as i32),
-                Point::new((end_x + 0.5) as i32, (end_y + 0.5) as i32),
-            )
-            .draw_styled(
+            let _ = Line::new(start, end).draw_styled(
                 &PrimitiveStyle::with_stroke(stroke_color, gauge_stroke_width / 2),
                 &mut context.draw_target,
             );
@@ -196,33 +551,293 @@ where
         // needle
         let needle_width = (circle.primitive.diameter / 10).clamp(1, 2) as f32;
 
-        let arrow_angle: f32 = (self.gauge_style.min_angle
-            + (self.gauge_style.max_angle - self.gauge_style.min_angle) * self.value)
-            .clamp(0.0, self.gauge_style.max_angle);
+        // keep the angle within one turn, so full-circle gauges point the same way at 0.0 and 1.0
+        let arrow_angle = self.gauge_style.value_angle(self.value) % 360.0;
+        let needle_color = if self.gauge_style.center_zero && self.value < 0.0 {
+            secondary_color
+        } else {
+            accent_color
+        };
         //println!("{} -> {}", self.value, arrow_angle);
-        let arrow_angle_rad = arrow_angle.to_radians() + (PI / 2.0);
-        let end_x = center.x as f32
-            + (circle_size as f32 / 2.0 + needle_width / 2.0) * arrow_angle_rad.cos();
-
-        let end_y = center.y as f32
-            + (circle_size as f32 / 2.0 + needle_width / 2.0) * arrow_angle_rad.sin();
-
-        let _ = Line::new(center, Point::new(end_x as i32, end_y as i32))
-            .into_styled(PrimitiveStyle::with_stroke(
-                accent_color,
-                needle_width as u32,
-            ))
-            .draw(&mut context.draw_target);
+        let (end_x, end_y) = arc::polar(
+            center,
+            circle_size as f32 / 2.0 + needle_width / 2.0,
+            arrow_angle + 90.0,
+        );
+
+        #[cfg(feature = "antialiasing")]
+        let needle_drawn = smooth.map(|(blend, face)| {
+            crate::antialias::draw_line(
+                &mut context.draw_target,
+                (center.x as f32 + 0.5, center.y as f32 + 0.5),
+                (end_x, end_y),
+                needle_width,
+                flash_color.unwrap_or(needle_color),
+                face,
+                blend,
+            )
+        });
+        #[cfg(not(feature = "antialiasing"))]
+        let needle_drawn: Option<()> = None;
+
+        if needle_drawn.is_none() {
+            let _ = Line::new(center, Point::new(end_x as i32, end_y as i32))
+                .into_styled(PrimitiveStyle::with_stroke(
+                    flash_color.unwrap_or(needle_color),
+                    needle_width as u32,
+                ))
+                .draw(&mut context.draw_target);
+        }
 
         // text
         let _ = Text::with_alignment(
             self.text,
             Point::new(center.x, center.y + 10),
-            MonoTextStyle::new(&FONT_4X6, accent_color),
+            MonoTextStyle::new(&FONT_4X6, secondary_color),
             Alignment::Center,
         )
         .draw(&mut context.draw_target);
 
+        if let Some(readout) = self.readout_text() {
+            let _ = Text::with_alignment(
+                &readout,
+                Point::new(center.x, center.y + 17),
+                MonoTextStyle::new(&FONT_4X6, foreground_color),
+                Alignment::Center,
+            )
+            .draw(&mut context.draw_target);
+        }
+
         EventResult::Pass
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mock_context;
+    use crate::themes::{hope_diamond, WidgetStyle};
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb888};
+
+    fn render_at_frame(gauge: &mut Gauge<'_, Rgb888>, frame: u32) -> MockDisplay<Rgb888> {
+        let mut ctx = mock_context();
+        ctx.draw_target.set_allow_out_of_bounds_drawing(true);
+        ctx.frame_counter = frame;
+
+        gauge.draw(
+            &mut ctx,
+            Rectangle::new(Point::zero(), Size::new(64, 64)),
+            WidgetEvent::default(),
+        );
+        ctx.draw_target
+    }
+
+    #[test]
+    fn startup_sweep_passes_full_scale() {
+        let style = GaugeStyle::default();
+        let angle_at = |frame| {
+            let mut gauge = Gauge::<Rgb888>::new(0.4, "RPM", style);
+            gauge.startup_sweep(frame, 20);
+            style.value_angle(gauge.value)
+        };
+
+        assert_eq!(angle_at(0), style.value_angle(0.0));
+        assert_eq!(angle_at(10), style.value_angle(1.0));
+        // settling back from full scale to the value
+        assert!(angle_at(15) > style.value_angle(0.4));
+        assert!(angle_at(15) < style.value_angle(1.0));
+        assert_eq!(angle_at(20), style.value_angle(0.4));
+    }
+
+    #[test]
+    fn center_zero_deflects_symmetrically() {
+        let style = GaugeStyle::default().center_zero(true);
+
+        // angles are measured from the bottom of the dial
+        assert_eq!(style.value_angle(0.0), 180.0);
+        assert_eq!(
+            style.value_angle(0.5) - 180.0,
+            180.0 - style.value_angle(-0.5)
+        );
+        assert_eq!(style.value_angle(-1.0), style.min_angle);
+        assert_eq!(style.value_angle(1.0), style.max_angle);
+    }
+
+    #[test]
+    fn adjacent_detents_share_boundary_pixel() {
+        let color = Rgb888::new(1, 2, 3);
+        let render_detent = |range| {
+            let mut gauge = Gauge::new(0.0, "", GaugeStyle::default());
+            gauge.add_detent(GaugeDetent::new(range, color));
+            render_at_frame(&mut gauge, 0)
+        };
+
+        let low = render_detent([0.4, 0.6]);
+        let high = render_detent([0.6, 0.8]);
+        let shared = Rectangle::new(Point::zero(), Size::new(64, 64))
+            .points()
+            .filter(|&point| {
+                low.get_pixel(point) == Some(color) && high.get_pixel(point) == Some(color)
+            })
+            .count();
+        assert!(shared > 0);
+    }
+
+    #[test]
+    fn redline_flashes_between_frames() {
+        let mut gauge = Gauge::new(0.9, "RPM", GaugeStyle::default());
+        gauge.add_detent(GaugeDetent::new([0.7, 1.0], Rgb888::RED));
+        gauge.redline = Some(0.8);
+        let period = gauge.gauge_style.flash_period;
+
+        assert_ne!(
+            render_at_frame(&mut gauge, 0),
+            render_at_frame(&mut gauge, period)
+        );
+
+        gauge.value = 0.5;
+        assert_eq!(
+            render_at_frame(&mut gauge, 0),
+            render_at_frame(&mut gauge, period)
+        );
+    }
+
+    #[test]
+    fn non_finite_values_render_as_range_ends() {
+        let mut zero = Gauge::<Rgb888>::new(0.0, "", GaugeStyle::default());
+        let mut full = Gauge::<Rgb888>::new(1.0, "", GaugeStyle::default());
+        let mut nan = Gauge::<Rgb888>::new(f32::NAN, "", GaugeStyle::default());
+        let mut infinity = Gauge::<Rgb888>::new(0.5, "", GaugeStyle::default());
+        infinity.value = f32::INFINITY;
+
+        assert_eq!(render_at_frame(&mut nan, 0), render_at_frame(&mut zero, 0));
+        assert_eq!(
+            render_at_frame(&mut infinity, 0),
+            render_at_frame(&mut full, 0)
+        );
+        assert_eq!(infinity.value, 1.0);
+    }
+
+    #[test]
+    fn center_zero_negative_infinity_renders_at_minimum() {
+        let style = GaugeStyle::default().center_zero(true);
+        let mut minimum = Gauge::<Rgb888>::new(-1.0, "", style);
+        let mut infinity = Gauge::<Rgb888>::new(f32::NEG_INFINITY, "", style);
+        assert_eq!(infinity.value, -1.0);
+
+        infinity.value = f32::NEG_INFINITY;
+        assert_eq!(
+            render_at_frame(&mut infinity, 0),
+            render_at_frame(&mut minimum, 0)
+        );
+        assert_eq!(infinity.value, -1.0);
+    }
+
+    #[cfg(feature = "antialiasing")]
+    #[test]
+    fn antialiased_detents_differ() {
+        let mut gauge = Gauge::new(0.5, "", GaugeStyle::default());
+        gauge.add_detent(GaugeDetent::new([0.0, 0.5], Rgb888::RED));
+        let aliased = render_at_frame(&mut gauge, 0);
+
+        gauge.set_antialiased(true);
+        assert_ne!(render_at_frame(&mut gauge, 0), aliased);
+    }
+
+    #[test]
+    fn full_circle_value_angles() {
+        let style = GaugeStyle::full_circle();
+
+        assert_eq!(style.value_angle(0.0), 180.0);
+        assert_eq!(style.value_angle(1.0), 180.0 + 360.0);
+        assert_eq!(style.value_angle(0.5), 180.0 + 180.0);
+        // out of range values stay within the span
+        assert_eq!(style.value_angle(-1.0), style.value_angle(0.0));
+        assert_eq!(style.value_angle(2.0), style.value_angle(1.0));
+
+        let mut zero = Gauge::<Rgb888>::new(0.0, "", GaugeStyle::full_circle());
+        let mut full = Gauge::<Rgb888>::new(1.0, "", GaugeStyle::full_circle());
+        assert_eq!(render_at_frame(&mut zero, 0), render_at_frame(&mut full, 0));
+    }
+
+    #[test]
+    fn readout_with_unit() {
+        let mut gauge = Gauge::<Rgb888>::new(0.5, "LOAD", GaugeStyle::default());
+        assert_eq!(gauge.readout_text(), None);
+
+        gauge.readout([0.0, 100.0], 0, "%");
+        assert_eq!(gauge.readout_text().as_deref(), Some("50%"));
+
+        let with_readout = render_at_frame(&mut gauge, 0);
+        gauge.readout = None;
+        assert_ne!(with_readout, render_at_frame(&mut gauge, 0));
+    }
+
+    #[test]
+    fn secondary_and_surface_colors() {
+        let base = WidgetStyle::new().accent_color(Rgb888::GREEN);
+        let merged = base.merge(
+            WidgetStyle::new()
+                .secondary_color(Rgb888::RED)
+                .surface_color(Rgb888::BLUE),
+        );
+        assert_eq!(merged.accent_color, Some(Rgb888::GREEN));
+        assert_eq!(merged.secondary_color, Some(Rgb888::RED));
+        assert_eq!(merged.surface_color, Some(Rgb888::BLUE));
+
+        let mut ctx = mock_context();
+        ctx.draw_target.set_allow_out_of_bounds_drawing(true);
+        ctx.theme.gauge_style = ctx.theme.gauge_style.merge(merged);
+
+        let rect = Rectangle::new(Point::zero(), Size::new(64, 64));
+        Gauge::<Rgb888>::new(0.5, "RPM", GaugeStyle::default()).draw(
+            &mut ctx,
+            rect,
+            WidgetEvent::default(),
+        );
+
+        let count = |color| {
+            rect.points()
+                .filter(|&point| ctx.draw_target.get_pixel(point) == Some(color))
+                .count()
+        };
+
+        // the label is drawn with the secondary color, the needle keeps the accent one
+        assert!(count(Rgb888::RED) > 0);
+        assert!(count(Rgb888::GREEN) > 0);
+        assert!(count(Rgb888::BLUE) > count(Rgb888::RED));
+    }
+
+    #[test]
+    fn label_placement_moves_labels() {
+        let center = Point::new(32, 32);
+        let angle = -90.0;
+        let inside = GaugeStyle::default().display_values(true);
+        let outside = inside.label_placement(GaugeLabelPlacement::Outside);
+
+        let inside_point = inside.label_center(center, 30.0, 6.0, angle);
+        let outside_point = outside.label_center(center, 30.0, 6.0, angle);
+        assert_eq!(inside_point.x, 32);
+        assert_eq!(inside_point.x, outside_point.x);
+        // the top division: outside labels are above the ring, inside ones below the ticks
+        assert!(outside_point.y < 2 && inside_point.y > 2 + 6);
+
+        let mut upright = Gauge::<Rgb888>::new(0.5, "", inside);
+        let mut rotated = Gauge::<Rgb888>::new(
+            0.5,
+            "",
+            inside.label_orientation(GaugeLabelOrientation::Rotated),
+        );
+        assert_ne!(
+            render_at_frame(&mut upright, 0),
+            render_at_frame(&mut rotated, 0)
+        );
+    }
+
+    #[test]
+    fn inverted_sweep_is_empty() {
+        let style = GaugeStyle::default().min_angle(300.0).max_angle(100.0);
+        assert_eq!(style.value_angle(0.0), 300.0);
+        assert_eq!(style.value_angle(1.0), 300.0);
+    }
+}
@@ -1,5 +1,8 @@
-use super::{UiBuilder, Widget, WidgetEvent, WidgetObject};
-use crate::{EventResult, SystemEvent, UiContext};
+use super::{
+    children_full, push_child, reserve_children, to_coord, Children, UiBuilder, Widget,
+    WidgetEvent, WidgetObject,
+};
+use crate::{EventResult, UiContext};
 use alloc::{boxed::Box, vec::Vec};
 use embedded_graphics::{prelude::*, primitives::Rectangle};
 
@@ -9,10 +12,12 @@ where
     D: DrawTarget<Color = C>,
     C: PixelColor,
 {
-    pub children: Vec<WidgetObject<'a, D, C>>,
+    pub children: Children<'a, D, C>,
     pub col_fracs: Vec<u32>,
     pub row_fracs: Vec<u32>,
     pub gap: u32,
+    /// Maximum number of children, `None` for unbounded. See [Self::capacity]
+    pub capacity: Option<usize>,
 }
 
 impl<D, C> GridLayoutBuilder<'_, D, C>
@@ -36,6 +41,13 @@ where
         self.gap = gap;
         self
     }
+
+    /// Limits the layout to `capacity` children, see [Children]
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        reserve_children(&mut self.children, capacity);
+        self.capacity = Some(capacity);
+        self
+    }
 }
 
 impl<D, C> Default for GridLayoutBuilder<'_, D, C>
@@ -45,10 +57,11 @@ where
 {
     fn default() -> Self {
         Self {
-            children: Vec::new(),
+            children: Children::new(),
             col_fracs: Vec::new(),
             row_fracs: Vec::new(),
             gap: 0,
+            capacity: None,
         }
     }
 }
@@ -59,7 +72,11 @@ where
     C: PixelColor + 'a,
 {
     fn add_widget_obj(&mut self, widget: WidgetObject<'a, D, C>) {
-        self.children.push(widget);
+        push_child(&mut self.children, self.capacity, widget);
+    }
+
+    fn is_full(&self) -> bool {
+        children_full(&self.children, self.capacity)
     }
 
     fn finish(self) -> WidgetObject<'a, D, C> {
@@ -77,7 +94,7 @@ where
     D: DrawTarget<Color = C>,
     C: PixelColor,
 {
-    pub children: Vec<WidgetObject<'a, D, C>>,
+    pub children: Children<'a, D, C>,
     pub col_fracs: Vec<u32>,
     pub row_fracs: Vec<u32>,
     pub gap: u32,
@@ -108,22 +125,22 @@ where
         let mut col_widths: Vec<u32> = self
             .col_fracs
             .iter()
-            .map(|&frac| available_width * frac / total_col)
+            .map(|&frac| (available_width as u64 * frac as u64 / total_col as u64) as u32)
             .collect();
 
         let mut row_heights: Vec<u32> = self
             .row_fracs
             .iter()
-            .map(|&frac| available_height * frac / total_row)
+            .map(|&frac| (available_height as u64 * frac as u64 / total_row as u64) as u32)
             .collect();
 
-        let total_actual_width: u32 = col_widths.iter().sum();
+        let total_actual_width = col_widths.iter().fold(0u32, |sum, w| sum.saturating_add(*w));
         if total_actual_width != available_width {
             col_widths[cols - 1] =
                 col_widths[cols - 1].saturating_add(available_width - total_actual_width);
         }
 
-        let total_actual_height: u32 = row_heights.iter().sum();
+        let total_actual_height = row_heights.iter().fold(0u32, |sum, h| sum.saturating_add(*h));
         if total_actual_height != available_height {
             row_heights[rows - 1] =
                 row_heights[rows - 1].saturating_add(available_height - total_actual_height);
@@ -136,18 +153,23 @@ where
                     break;
                 }
 
-                let x_offset: i32 = col_widths[..c]
-                    .iter()
-                    .map(|w| *w as i32 + self.gap as i32)
-                    .sum();
+                let x_offset = col_widths[..c].iter().fold(0i32, |offset, w| {
+                    offset
+                        .saturating_add(to_coord(*w))
+                        .saturating_add(to_coord(self.gap))
+                });
 
-                let y_offset: i32 = row_heights[..r]
-                    .iter()
-                    .map(|h| *h as i32 + self.gap as i32)
-                    .sum();
+                let y_offset = row_heights[..r].iter().fold(0i32, |offset, h| {
+                    offset
+                        .saturating_add(to_coord(*h))
+                        .saturating_add(to_coord(self.gap))
+                });
 
                 let cell_rect = Rectangle::new(
-                    rect.top_left + Point::new(x_offset, y_offset),
+                    Point::new(
+                        rect.top_left.x.saturating_add(x_offset),
+                        rect.top_left.y.saturating_add(y_offset),
+                    ),
                     Size::new(col_widths[c], row_heights[r]),
                 );
 
@@ -165,11 +187,7 @@ where
         let mut event_result = EventResult::Pass;
 
         for child in self.children.iter_mut() {
-            if event_result == EventResult::Stop {
-                event_result = child.draw(context, &SystemEvent::Idle);
-            } else {
-                event_result = child.draw(context, event_args.system_event);
-            }
+            child.draw_sibling(context, event_args.system_event, &mut event_result);
         }
 
         event_result
@@ -0,0 +1,145 @@
+use crate::{EventResult, UiContext};
+
+use super::{Widget, WidgetEvent};
+use alloc::{format, vec::Vec};
+use embedded_graphics::{
+    mono_font::{ascii::FONT_4X6, MonoTextStyle},
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+    text::{Baseline, Text},
+};
+
+/// Histogram widget. Draws bin counts as vertical bars, normalized so the tallest bin fills the whole height
+pub struct Histogram {
+    pub bins: Vec<u32>,
+    /// Draws the maximum and zero count along the left edge
+    pub show_labels: bool,
+}
+
+impl Histogram {
+    pub fn new(bins: Vec<u32>) -> Self {
+        Self {
+            bins,
+            show_labels: false,
+        }
+    }
+
+    fn label_width(&self) -> u32 {
+        if !self.show_labels {
+            return 0;
+        }
+
+        let max = self.bins.iter().copied().max().unwrap_or(0);
+        let digits = format!("{}", max).len() as u32;
+        digits * FONT_4X6.character_size.width + 1
+    }
+
+    fn bar_rects(&self, rect: Rectangle) -> Vec<Rectangle> {
+        let max = self.bins.iter().copied().max().unwrap_or(0);
+        if self.bins.is_empty() || max == 0 {
+            return Vec::new();
+        }
+
+        let label_width = self.label_width();
+        let area_width = rect.size.width.saturating_sub(label_width);
+        let bar_width = area_width / self.bins.len() as u32;
+        // leave a pixel between bars if there is enough space for it
+        let gap = if bar_width > 2 { 1 } else { 0 };
+
+        self.bins
+            .iter()
+            .enumerate()
+            .map(|(i, &count)| {
+                let height = (rect.size.height as u64 * count as u64 / max as u64) as u32;
+                Rectangle::new(
+                    Point::new(
+                        rect.top_left.x + (label_width + i as u32 * bar_width) as i32,
+                        rect.top_left.y + (rect.size.height - height) as i32,
+                    ),
+                    Size::new(bar_width - gap, height),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<'a, D, C> Widget<'a, D, C> for Histogram
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor + 'a,
+{
+    fn size(&mut self, _context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
+        hint
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        _event_args: WidgetEvent,
+    ) -> EventResult {
+        let style = context.theme.plot_style;
+        let bar_color = style.curve_color;
+
+        for bar in self.bar_rects(rect) {
+            let _ = bar
+                .into_styled(PrimitiveStyle::with_fill(bar_color))
+                .draw(&mut context.draw_target);
+        }
+
+        if self.show_labels {
+            let text_style = MonoTextStyle::new(&FONT_4X6, bar_color);
+            let max = self.bins.iter().copied().max().unwrap_or(0);
+
+            let _ = Text::with_baseline(
+                &format!("{}", max),
+                rect.top_left,
+                text_style,
+                Baseline::Top,
+            )
+            .draw(&mut context.draw_target);
+
+            let _ = Text::with_baseline(
+                "0",
+                Point::new(rect.top_left.x, rect.top_left.y + rect.size.height as i32),
+                text_style,
+                Baseline::Bottom,
+            )
+            .draw(&mut context.draw_target);
+        }
+
+        EventResult::Pass
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn bars_normalized_to_tallest_bin() {
+        let histogram = Histogram::new(vec![2, 4, 1, 0]);
+        let rect = Rectangle::new(Point::new(5, 5), Size::new(40, 40));
+        let bars = histogram.bar_rects(rect);
+
+        assert_eq!(bars.len(), 4);
+        assert_eq!(bars[1].size.height, 40);
+        assert_eq!(bars[1].top_left.y, 5);
+        assert_eq!(bars[0].size.height, 20);
+        assert_eq!(bars[2].size.height, 10);
+        assert_eq!(bars[3].size.height, 0);
+
+        // every bar sits on the bottom edge
+        for bar in bars {
+            assert_eq!(bar.top_left.y + bar.size.height as i32, 45);
+        }
+    }
+
+    #[test]
+    fn empty_histogram_draws_nothing() {
+        let rect = Rectangle::new(Point::zero(), Size::new(40, 40));
+        assert!(Histogram::new(vec![0, 0]).bar_rects(rect).is_empty());
+        assert!(Histogram::new(Vec::new()).bar_rects(rect).is_empty());
+    }
+}
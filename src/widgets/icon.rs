@@ -0,0 +1,85 @@
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+use super::{Widget, WidgetEvent};
+use crate::{themes::icons::Symbol, EventResult, UiContext};
+
+/// Draws a built-in [Symbol] scaled to the computed rect
+pub struct Icon<C: PixelColor> {
+    pub symbol: Symbol,
+    /// Symbol color, defaults to the theme label color
+    pub color: Option<C>,
+    /// Cutout color, transparent by default
+    pub background: Option<C>,
+    size: Size,
+}
+
+impl<C: PixelColor> Icon<C> {
+    pub fn new(symbol: Symbol, size: Size) -> Self {
+        Self {
+            symbol,
+            color: None,
+            background: None,
+            size,
+        }
+    }
+
+    pub fn color(mut self, color: C) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn background(mut self, color: C) -> Self {
+        self.background = Some(color);
+        self
+    }
+}
+
+impl<'a, D, C> Widget<'a, D, C> for Icon<C>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor + 'a,
+{
+    fn size(&mut self, _context: &mut UiContext<'a, D, C>, _hint: Size) -> Size {
+        self.size
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        _event_args: WidgetEvent,
+    ) -> EventResult {
+        let color = self.color.unwrap_or(context.theme.label_color);
+        self.symbol
+            .draw(&mut context.draw_target, rect, color, self.background);
+        EventResult::Pass
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mock_context;
+    use embedded_graphics::pixelcolor::Rgb888;
+
+    #[test]
+    fn warning_icon_draws_filled_triangle() {
+        let mut ctx = mock_context::<Rgb888>();
+        let mut icon = Icon::new(Symbol::Warning, Size::new(21, 21)).color(Rgb888::YELLOW);
+
+        icon.draw(
+            &mut ctx,
+            Rectangle::new(Point::zero(), Size::new(21, 21)),
+            WidgetEvent::default(),
+        );
+
+        let display = &ctx.draw_target;
+        // apex, interior and both base corners
+        for point in [(10, 0), (10, 12), (1, 20), (19, 20)] {
+            assert_eq!(display.get_pixel(point.into()), Some(Rgb888::YELLOW));
+        }
+        // outside of the triangle slopes
+        assert_eq!(display.get_pixel(Point::new(1, 2)), None);
+        assert_eq!(display.get_pixel(Point::new(19, 2)), None);
+    }
+}
@@ -36,10 +36,32 @@ where
         rect: Rectangle,
         _event_args: WidgetEvent,
     ) -> EventResult {
-        let _ = self
-            .image
-            .draw(&mut context.draw_target.translated(rect.top_left));
+        let _ = self.image.draw(
+            &mut context
+                .draw_target
+                .clipped(&rect)
+                .translated(rect.top_left),
+        );
 
         EventResult::Pass
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mock_context;
+    use embedded_graphics::{image::ImageRaw, pixelcolor::BinaryColor};
+
+    #[test]
+    fn image_is_clipped_to_rect() {
+        let data = [0xFF; 8];
+        let raw = ImageRaw::<BinaryColor>::new(&data, 8);
+        let mut ctx = mock_context::<BinaryColor>();
+
+        let rect = Rectangle::new(Point::new(2, 2), Size::new(4, 4));
+        Image::new(&raw).draw(&mut ctx, rect, WidgetEvent::default());
+
+        assert_eq!(ctx.draw_target.affected_area(), rect);
+    }
+}
@@ -0,0 +1,263 @@
+use alloc::boxed::Box;
+use core::cell::Cell;
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Circle, Line, PrimitiveStyle, Rectangle},
+};
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
+use super::{Widget, WidgetEvent};
+use crate::{themes::arc, Event, EventResult, SystemEvent, UiContext};
+
+/// Persistent state of the [JogDial], keep it outside of the UI tree because the dial counts
+/// detents between drag events of different frames. The dial borrows it shared, so the same state
+/// can be passed to the dial rebuilt every frame
+#[derive(Clone, Debug, Default)]
+pub struct JogDialState {
+    /// Pointer angle of the previous drag event, `None` when the dial is not dragged
+    last_angle: Cell<Option<f32>>,
+    /// Rotation since the last emitted step in degrees
+    accumulated: Cell<f32>,
+    /// Total dial rotation in degrees, used for the marker
+    rotation: Cell<f32>,
+}
+
+impl JogDialState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the total dial rotation in degrees, clockwise is positive
+    pub fn rotation(&self) -> f32 {
+        self.rotation.get()
+    }
+
+    /// Ends the drag, the next drag event starts a new gesture
+    fn release(&self) {
+        self.last_angle.set(None);
+        self.accumulated.set(0.0);
+    }
+}
+
+/// Jog wheel with detent ticks. Dragging around the center emits an `Increase` (clockwise)
+/// or `Decrease` (counter-clockwise) step every time the pointer crosses a detent, instead of
+/// reporting an absolute value. The step value is the fraction of a turn per detent
+pub struct JogDial<'a> {
+    state: &'a JogDialState,
+    detents: u32,
+    callback: Box<dyn FnMut(SystemEvent) + 'a>,
+}
+
+impl<'a> JogDial<'a> {
+    pub fn new(
+        state: &'a JogDialState,
+        detents: u32,
+        callback: Box<dyn FnMut(SystemEvent) + 'a>,
+    ) -> Self {
+        Self {
+            state,
+            detents: detents.max(1),
+            callback,
+        }
+    }
+
+    fn detent_angle(&self) -> f32 {
+        360.0 / self.detents as f32
+    }
+
+    /// Feeds a pointer angle (degrees) from the drag event, emitting steps for crossed detents
+    fn drag_to(&mut self, angle: f32) {
+        let Some(last_angle) = self.state.last_angle.replace(Some(angle)) else {
+            return;
+        };
+
+        let mut delta = angle - last_angle;
+        if delta > 180.0 {
+            delta -= 360.0;
+        } else if delta < -180.0 {
+            delta += 360.0;
+        }
+
+        self.state.rotation.set(self.state.rotation.get() + delta);
+        let mut accumulated = self.state.accumulated.get() + delta;
+
+        let detent = self.detent_angle();
+        let step = 1.0 / self.detents as f32;
+        while accumulated >= detent {
+            accumulated -= detent;
+            (self.callback)(SystemEvent::Increase(step));
+        }
+        while accumulated <= -detent {
+            accumulated += detent;
+            (self.callback)(SystemEvent::Decrease(step));
+        }
+        self.state.accumulated.set(accumulated);
+    }
+
+    /// Feeds the pointer `position` relative to the dial rect
+    fn drag_at(&mut self, rect: Rectangle, position: Point) {
+        let offset = position - Point::new(rect.size.width as i32 / 2, rect.size.height as i32 / 2);
+        if offset != Point::zero() {
+            self.drag_to((offset.y as f32).atan2(offset.x as f32).to_degrees());
+        }
+    }
+}
+
+impl<'a, D, C> Widget<'a, D, C> for JogDial<'a>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor + 'a,
+{
+    fn size(&mut self, _context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
+        let side = hint.width.min(hint.height);
+        Size::new(side, side)
+    }
+
+    fn is_interactive(&mut self) -> bool {
+        true
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        event_args: WidgetEvent,
+    ) -> EventResult {
+        let event_result = match event_args.event {
+            Event::Focus => EventResult::Stop,
+            Event::Drag(position) => {
+                context.focused_element = event_args.id;
+                self.drag_at(rect, *position);
+                EventResult::Stop
+            }
+            // the press is reported when the pointer is released
+            Event::Active(Some(position)) => {
+                context.focused_element = event_args.id;
+                self.drag_at(rect, *position);
+                self.state.release();
+                EventResult::Stop
+            }
+            _ => {
+                // frames without input keep the gesture, a release or a move outside ends it
+                if matches!(
+                    event_args.system_event,
+                    SystemEvent::Active(_) | SystemEvent::Move(_)
+                ) {
+                    self.state.release();
+                }
+                EventResult::Pass
+            }
+        };
+
+        let style = context.theme.gauge_style;
+        let foreground_color = style
+            .foreground_color
+            .expect("JogDial must have a foreground color to draw");
+        let stroke_color = style.stroke_color.unwrap_or(foreground_color);
+
+        let diameter = rect.size.width.min(rect.size.height);
+        let circle = Circle::with_center(rect.center(), diameter.saturating_sub(2));
+        let _ = circle
+            .into_styled(PrimitiveStyle::with_stroke(stroke_color, 1))
+            .draw(&mut context.draw_target);
+
+        let center = circle.center();
+        let radius = circle.diameter as f32 / 2.0;
+        let tick_length = (radius * 0.2).max(1.0);
+        for detent in 0..self.detents {
+            let angle = detent as f32 * 360.0 / self.detents as f32;
+            let (outer, inner) = arc::tick_endpoints(center, radius, tick_length, angle);
+            let _ = Line::new(outer, inner)
+                .into_styled(PrimitiveStyle::with_stroke(stroke_color, 1))
+                .draw(&mut context.draw_target);
+        }
+
+        let marker_color = style.accent_color.unwrap_or(foreground_color);
+        let marker = arc::polar_point(
+            center,
+            radius - tick_length * 2.0,
+            self.state.rotation() - 90.0,
+        );
+        let _ = Circle::with_center(marker, (diameter / 10).clamp(2, 4))
+            .into_styled(PrimitiveStyle::with_fill(marker_color))
+            .draw(&mut context.draw_target);
+
+        event_result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::mock_context,
+        widgets::{linear_layout::LinearLayoutBuilder, UiBuilder},
+    };
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+    use embedded_graphics::pixelcolor::Rgb888;
+
+    /// Pointer position at `angle` degrees on a circle around `center`
+    fn on_circle(center: Point, angle: f32) -> Point {
+        arc::polar_point(center, 15.0, angle)
+    }
+
+    #[test]
+    fn dragging_through_three_detents_emits_three_steps() {
+        let rect = Rectangle::new(Point::zero(), Size::new(40, 40));
+        let emitted = RefCell::new(Vec::new());
+        let state = JogDialState::new();
+
+        // pointer positions every 35 degrees clockwise, starting at 3 o'clock
+        for angle in [0.0f32, 35.0, 70.0, 105.0, 140.0] {
+            let mut ctx = mock_context::<Rgb888>();
+            let mut dial = JogDial::new(
+                &state,
+                8,
+                Box::new(|event| emitted.borrow_mut().push(event)),
+            );
+            dial.draw(
+                &mut ctx,
+                rect,
+                WidgetEvent {
+                    event: &Event::Drag(on_circle(Point::new(20, 20), angle)),
+                    ..Default::default()
+                },
+            );
+        }
+
+        assert_eq!(*emitted.borrow(), [SystemEvent::Increase(0.125); 3]);
+        assert!(state.rotation() > 135.0);
+    }
+
+    #[test]
+    fn drag_survives_idle_frames_and_ends_on_release() {
+        let emitted = RefCell::new(Vec::new());
+        let state = JogDialState::new();
+        let mut ctx = mock_context::<Rgb888>();
+        // the dial fills the 64x64 display
+        let at = |angle| on_circle(Point::new(32, 32), angle);
+        let build = || {
+            let mut ui = LinearLayoutBuilder::default();
+            ui.add_widget(JogDial::new(
+                &state,
+                8,
+                Box::new(|event| emitted.borrow_mut().push(event)),
+            ));
+            ui.finish()
+        };
+
+        ctx.simulate(build(), SystemEvent::Drag(at(0.0)));
+        // no input while the pointer is held still
+        ctx.update(build());
+        ctx.simulate(build(), SystemEvent::Drag(at(50.0)));
+        assert_eq!(*emitted.borrow(), [SystemEvent::Increase(0.125)]);
+
+        // released, the next drag starts a new gesture
+        ctx.simulate(build(), SystemEvent::Active(at(50.0)));
+        ctx.simulate(build(), SystemEvent::Drag(at(180.0)));
+        ctx.simulate(build(), SystemEvent::Drag(at(200.0)));
+        assert_eq!(emitted.borrow().len(), 1);
+    }
+}
@@ -1,4 +1,5 @@
 use alloc::string::String;
+use core::marker::PhantomData;
 use embedded_graphics::{
     mono_font::{MonoFont, MonoTextStyle, MonoTextStyleBuilder},
     prelude::*,
@@ -6,8 +7,8 @@ use embedded_graphics::{
     text::{renderer::TextRenderer, Alignment, Baseline, Text, TextStyleBuilder},
 };
 
-use super::{Widget, WidgetEvent};
-use crate::{EventResult, UiContext};
+use super::{to_coord, Widget, WidgetEvent};
+use crate::{text::TextMeasure, themes::WidgetStyle, EventResult, UiContext};
 
 /// Re-export of type [SevenSegmentStyle] from [eg_seven_segment]
 pub use eg_seven_segment::SevenSegmentStyle;
@@ -39,13 +40,14 @@ where
         let mut total_height = 0;
 
         for line in self.text.lines() {
-            let line_rect = self
+            let line_size = self
                 .style
                 .measure_string(line, Point::zero(), Baseline::Top)
-                .bounding_box;
+                .bounding_box
+                .size;
 
-            total_width = total_width.max(line_rect.size.width);
-            total_height += line_rect.size.height;
+            total_width = total_width.max(line_size.width);
+            total_height += line_size.height;
         }
 
         Size::new(total_width, total_height)
@@ -63,13 +65,15 @@ where
     }
 }
 
-/// Advanced label format options
+/// Advanced label format options, build them with [LabelOptions::new] and the setters
 #[derive(Clone, Copy)]
 pub struct LabelOptions {
     /// Horizontal alignment for label
     pub alignment: Alignment,
     // Line height, left `None`` for auto-computation
     pub line_height: Option<u32>,
+    /// Space between the text and the label rect on every side
+    pub padding: u32,
 }
 
 impl Default for LabelOptions {
@@ -83,6 +87,7 @@ impl LabelOptions {
         Self {
             alignment: Alignment::Left,
             line_height: None,
+            padding: 0,
         }
     }
 
@@ -95,6 +100,11 @@ impl LabelOptions {
         self.line_height = Some(height);
         self
     }
+
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
 }
 
 impl From<Alignment> for LabelOptions {
@@ -106,48 +116,45 @@ impl From<Alignment> for LabelOptions {
     }
 }
 
-/// Label widget
-pub struct Label<'a, C: PixelColor> {
+/// Label widget. The text is drawn with a [TextMeasure] style, a [MonoTextStyle] by default
+pub struct Label<'a, C: PixelColor, S = MonoTextStyle<'a, C>> {
     text: String,
-    style: MonoTextStyle<'a, C>,
+    style: S,
     options: LabelOptions,
+    /// Candidate styles, see [Label::fit_fonts]
+    fit_fonts: &'a [S],
+    color: PhantomData<C>,
 }
 
 impl<'a, C> Label<'a, C>
 where
     C: PixelColor + 'a,
 {
-    pub fn new<S: Into<String>>(text: S, options: LabelOptions, font: &'a MonoFont) -> Self {
-        Self {
-            text: text.into(),
-            options,
-            style: MonoTextStyleBuilder::new().font(font).build(),
-        }
-    }
-
-    pub fn new_with_style<S: Into<String>>(
-        text: S,
-        options: LabelOptions,
-        style: MonoTextStyle<'a, C>,
-    ) -> Self {
-        Self {
-            text: text.into(),
+    pub fn new<T: Into<String>>(text: T, options: LabelOptions, font: &'a MonoFont) -> Self {
+        Self::new_with_style(
+            text,
             options,
-            style,
-        }
+            MonoTextStyleBuilder::new().font(font).build(),
+        )
     }
 }
 
-impl<'a, D, C> Widget<'a, D, C> for Label<'a, C>
+impl<'a, C, S> Label<'a, C, S>
 where
-    D: DrawTarget<Color = C>,
     C: PixelColor + 'a,
+    S: TextMeasure + TextRenderer<Color = C>,
 {
-    fn size(&mut self, context: &mut UiContext<'a, D, C>, _hint: Size) -> Size {
-        if self.style.text_color.is_none() {
-            self.style.text_color = Some(context.theme.label_color);
-        }
+    /// Shrinks the text to fit: the label is drawn with the largest (by line height) of `fonts`
+    /// fitting the available space (the smallest one if none fits), so long texts in fixed-size
+    /// cells shrink instead of overflowing. Only the fonts are taken from the candidates, the
+    /// colors stay the label ones
+    pub fn fit_fonts(mut self, fonts: &'a [S]) -> Self {
+        self.fit_fonts = fonts;
+        self
+    }
 
+    /// Returns the size of the whole (possibly multiline) text with the current font
+    fn text_size<D: DrawTarget<Color = C>>(&self, context: &mut UiContext<'a, D, C>) -> Size {
         if self.text.is_empty() {
             return Size::zero();
         }
@@ -165,41 +172,123 @@ where
         if line_count > 1 {
             // multiline case
             for (i, line) in self.text.lines().into_iter().enumerate() {
-                let line_rect = self
-                    .style
-                    .measure_string(line, Point::zero(), embedded_graphics::text::Baseline::Top)
-                    .bounding_box;
+                let line_size = context.measure_text(&self.style, line);
 
-                total_width = total_width.max(line_rect.size.width);
+                total_width = total_width.max(line_size.width);
 
                 // do not count the last line, because this creates a bottom padding in the text and in general is very bad thing...
                 if i != line_count - 1 {
-                    total_height += line_rect.size.height + line_spacing;
+                    total_height += line_size.height + line_spacing;
                 }
             }
         } else {
             // single line case
-            let text_rect = self
-                .style
-                .measure_string(
-                    &self.text,
-                    Point::zero(),
-                    embedded_graphics::text::Baseline::Top,
-                )
-                .bounding_box;
-            total_height = text_rect.size.height;
-            total_width = text_rect.size.width;
+            let text_size = context.measure_text(&self.style, &self.text);
+            total_height = text_size.height;
+            total_width = text_size.width;
         }
 
         Size::new(total_width, total_height)
     }
 
+    /// Switches to the largest fit font for which the text fits in `available`. Runs in the
+    /// measure pass only, drawing keeps the chosen font
+    fn fit_font<D: DrawTarget<Color = C>>(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        available: Size,
+    ) {
+        let text_color = self.style.text_color();
+        let background_color = self.style.background_color();
+        let mut best: Option<&'a S> = None;
+        let mut smallest: Option<&'a S> = None;
+
+        for candidate in self.fit_fonts {
+            self.style = candidate.clone();
+            let size = self.text_size(context);
+            let height = candidate.line_height();
+            if size.width <= available.width
+                && size.height <= available.height
+                && best.is_none_or(|best| height > best.line_height())
+            {
+                best = Some(candidate);
+            }
+
+            if smallest.is_none_or(|smallest| height < smallest.line_height()) {
+                smallest = Some(candidate);
+            }
+        }
+
+        if let Some(style) = best.or(smallest) {
+            self.style = style.clone();
+        }
+        self.style.set_text_color(text_color);
+        self.style.set_background_color(background_color);
+    }
+
+    /// Overrides the text color with the foreground color and the text background with the
+    /// background color of `style`, if set. The rest comes from the theme
+    pub fn style(mut self, style: WidgetStyle<C>) -> Self {
+        if let Some(color) = style.foreground_color {
+            self.style.set_text_color(Some(color));
+        }
+
+        if let Some(color) = style.background_color {
+            self.style.set_background_color(Some(color));
+        }
+
+        self
+    }
+
+    /// Replaces the label text. The size changes, so the owning [super::WidgetObject] must be
+    /// measured again
+    pub fn set_text<T: Into<String>>(&mut self, text: T) {
+        self.text = text.into();
+    }
+
+    /// Creates a label drawn with `style`, any [TextMeasure] text style
+    pub fn new_with_style<T: Into<String>>(text: T, options: LabelOptions, style: S) -> Self {
+        Self {
+            text: text.into(),
+            options,
+            style,
+            fit_fonts: &[],
+            color: PhantomData,
+        }
+    }
+}
+
+impl<'a, D, C, S> Widget<'a, D, C> for Label<'a, C, S>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor + 'a,
+    S: TextMeasure + TextRenderer<Color = C> + 'a,
+{
+    fn size(&mut self, context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
+        if self.style.text_color().is_none() {
+            self.style.set_text_color(Some(context.theme.label_color));
+        }
+
+        let padding = Size::new(2 * self.options.padding, 2 * self.options.padding);
+        if !self.fit_fonts.is_empty() {
+            self.fit_font(context, hint.saturating_sub(padding));
+        }
+
+        let text_size = self.text_size(context);
+        if text_size == Size::zero() {
+            return text_size;
+        }
+
+        text_size.saturating_add(padding)
+    }
+
     fn draw(
         &mut self,
         context: &mut UiContext<'a, D, C>,
         rect: Rectangle,
         _event_args: WidgetEvent,
     ) -> EventResult {
+        let rect = rect.offset(-to_coord(self.options.padding));
         let mut position = rect.top_left;
 
         match self.options.alignment {
@@ -218,7 +307,7 @@ where
         let text = Text::with_text_style(
             &self.text,
             position,
-            self.style,
+            self.style.clone(),
             TextStyleBuilder::new()
                 .alignment(self.options.alignment)
                 .baseline(Baseline::Top)
@@ -234,13 +323,16 @@ mod tests {
     use super::*;
     use crate::{
         prelude::*,
+        testing::mock_context,
         themes::hope_diamond::{self},
-        widgets::linear_layout::LinearLayoutBuilder,
+        widgets::{linear_layout::LinearLayoutBuilder, WidgetObject},
         SystemEvent,
     };
+    use alloc::{boxed::Box, rc::Rc};
+    use core::cell::RefCell;
     use embedded_graphics::{
         mock_display::MockDisplay,
-        mono_font::ascii::{FONT_10X20, FONT_4X6},
+        mono_font::ascii::{FONT_10X20, FONT_4X6, FONT_6X10},
         pixelcolor::Rgb888,
     };
 
@@ -288,6 +380,99 @@ mod tests {
         assert_eq!(size.height, 0);
     }
 
+    #[test]
+    fn measuring_same_label_twice_hits_cache() {
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.set_text_cache(4);
+
+        let mut label = Label::new("cached", LabelOptions::default(), &FONT_10X20);
+        let first = label.size(&mut ctx, Size::new(320, 240));
+        assert_eq!(ctx.text_cache_hits(), 0);
+
+        let second = label.size(&mut ctx, Size::new(320, 240));
+        assert_eq!(ctx.text_cache_hits(), 1);
+        assert_eq!(first, second);
+        assert_eq!(second, Size::new(60, 20));
+    }
+
+    #[test]
+    fn padding_insets_text() {
+        let mut ctx = mock_context::<Rgb888>();
+        let mut label = Label::new("#", LabelOptions::new().padding(4), &FONT_4X6);
+
+        let size = label.size(&mut ctx, Size::new(64, 64));
+        assert_eq!(size, Size::new(4 + 8, 6 + 8));
+
+        label.draw(
+            &mut ctx,
+            Rectangle::new(Point::zero(), size),
+            WidgetEvent::default(),
+        );
+        let drawn = ctx.draw_target.affected_area();
+        assert!(drawn.top_left.x >= 4 && drawn.top_left.y >= 4);
+        let bottom_right = drawn.bottom_right().unwrap();
+        assert!(bottom_right.x < 8 && bottom_right.y < 10);
+    }
+
+    #[test]
+    fn long_text_picks_smaller_font() {
+        let fonts = [&FONT_4X6, &FONT_10X20, &FONT_6X10]
+            .map(|font| MonoTextStyle::new(font, Rgb888::WHITE));
+        let mut ctx = mock_context::<Rgb888>();
+        let cell = Size::new(40, 20);
+
+        let mut short = Label::new("OK", LabelOptions::default(), &FONT_4X6).fit_fonts(&fonts);
+        assert_eq!(short.size(&mut ctx, cell), Size::new(20, 20));
+        assert_eq!(short.style.font, &FONT_10X20);
+
+        let mut long = Label::new("ENGINE", LabelOptions::default(), &FONT_4X6).fit_fonts(&fonts);
+        assert_eq!(long.size(&mut ctx, cell), Size::new(36, 10));
+        assert_eq!(long.style.font, &FONT_6X10);
+
+        // drawing keeps the font measured for the layout
+        short.draw(
+            &mut ctx,
+            Rectangle::new(Point::zero(), Size::new(10, 10)),
+            WidgetEvent::default(),
+        );
+        assert_eq!(short.style.font, &FONT_10X20);
+    }
+
+    /// Retained label shared between frames
+    struct SharedLabel<'a>(Rc<RefCell<Label<'a, Rgb888>>>);
+
+    impl<'a, D> Widget<'a, D, Rgb888> for SharedLabel<'a>
+    where
+        D: DrawTarget<Color = Rgb888>,
+    {
+        fn size(&mut self, context: &mut UiContext<'a, D, Rgb888>, hint: Size) -> Size {
+            self.0.borrow_mut().size(context, hint)
+        }
+    }
+
+    #[test]
+    fn changed_text_is_remeasured() {
+        let mut ctx = mock_context::<Rgb888>();
+        let label = Rc::new(RefCell::new(Label::new(
+            "ab",
+            LabelOptions::default(),
+            &FONT_4X6,
+        )));
+        let mut object = WidgetObject::new(Box::new(SharedLabel(label.clone())));
+        let hint = Size::new(64, 64);
+
+        assert_eq!(object.size(&mut ctx, hint), Size::new(8, 6));
+
+        label.borrow_mut().set_text("abcd");
+        assert_eq!(object.size(&mut ctx, hint), Size::new(8, 6));
+        object.invalidate();
+        assert_eq!(object.size(&mut ctx, hint), Size::new(16, 6));
+
+        label.borrow_mut().set_text("a");
+        ctx.update(LinearLayoutBuilder::default().finish());
+        assert_eq!(object.size(&mut ctx, hint), Size::new(4, 6));
+    }
+
     #[test]
     fn center_alignment_draws_in_bounds() {
         let display = MockDisplay::<Rgb888>::new();
@@ -1,9 +1,12 @@
-use alloc::{boxed::Box, vec::Vec};
+use alloc::boxed::Box;
 use embedded_graphics::{prelude::*, primitives::Rectangle};
 
-use crate::{themes::WidgetStyle, EventResult, SystemEvent, UiContext};
+use crate::{themes::WidgetStyle, EventResult, UiContext};
 
-use super::{UiBuilder, Widget, WidgetEvent, WidgetObject};
+use super::{
+    children_full, filler::FillStrategy, push_child, reserve_children, to_coord, Children,
+    UiBuilder, Widget, WidgetEvent, WidgetObject,
+};
 
 #[derive(PartialEq, Clone, Copy)]
 pub enum LayoutDirection {
@@ -25,7 +28,7 @@ where
     D: DrawTarget<Color = C>,
     C: PixelColor,
 {
-    pub children: Vec<WidgetObject<'a, D, C>>,
+    pub children: Children<'a, D, C>,
     pub horizontal_alignment: LayoutAlignment,
     pub vertical_alignment: LayoutAlignment,
     pub direction: LayoutDirection,
@@ -33,6 +36,14 @@ where
     pub min_size: Size,
     pub gap: u32,
     pub max_size: Size,
+    /// Maximum number of children, `None` for unbounded. See [Self::capacity]
+    pub capacity: Option<usize>,
+    /// See [Self::clear_background]
+    pub clear_background: bool,
+    /// See [Self::disabled]
+    pub disabled: bool,
+    /// See [Self::uniform]
+    pub uniform: bool,
 }
 
 impl<D, C> LinearLayoutBuilder<'_, D, C>
@@ -94,6 +105,36 @@ where
         self.gap = gap;
         self
     }
+
+    /// Limits the layout to `capacity` children, see [Children]
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        reserve_children(&mut self.children, capacity);
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Clears the layout rect every frame, with the resolved background color (see
+    /// [UiContext::background_color]) if the style has no background. Prevents ghosting: without
+    /// a background, pixels of a child which shrank stay on the screen
+    pub fn clear_background(mut self, clear: bool) -> Self {
+        self.clear_background = clear;
+        self
+    }
+
+    /// Disables the children (nested layouts too): they get only idle events and are drawn with
+    /// the disabled style, see [UiContext::is_disabled]. Children of a disabled layout can not be
+    /// enabled again
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Gives every child the main axis length of the longest one, e.g. a row of buttons with
+    /// different texts gets buttons of the same width. Fillers are not affected
+    pub fn uniform(mut self, uniform: bool) -> Self {
+        self.uniform = uniform;
+        self
+    }
 }
 
 impl<D, C> Default for LinearLayoutBuilder<'_, D, C>
@@ -103,7 +144,7 @@ where
 {
     fn default() -> Self {
         Self {
-            children: Vec::new(),
+            children: Children::new(),
             horizontal_alignment: LayoutAlignment::Start,
             vertical_alignment: LayoutAlignment::Start,
             style: WidgetStyle::default(),
@@ -111,6 +152,10 @@ where
             min_size: Size::zero(),
             gap: 0,
             max_size: Size::new(u32::MAX, u32::MAX),
+            capacity: None,
+            clear_background: false,
+            disabled: false,
+            uniform: false,
         }
     }
 }
@@ -121,7 +166,11 @@ where
     C: PixelColor + 'a,
 {
     fn add_widget_obj(&mut self, widget: WidgetObject<'a, D, C>) {
-        self.children.push(widget);
+        push_child(&mut self.children, self.capacity, widget);
+    }
+
+    fn is_full(&self) -> bool {
+        children_full(&self.children, self.capacity)
     }
 
     fn finish(self) -> WidgetObject<'a, D, C> {
@@ -134,6 +183,9 @@ where
             gap: self.gap,
             min_size: self.min_size,
             max_size: self.max_size,
+            clear_background: self.clear_background,
+            disabled: self.disabled,
+            uniform: self.uniform,
         }))
     }
 }
@@ -144,7 +196,7 @@ where
     D: DrawTarget<Color = C>,
     C: PixelColor,
 {
-    children: Vec<WidgetObject<'a, D, C>>,
+    children: Children<'a, D, C>,
     direction: LayoutDirection,
     horizontal_alignment: LayoutAlignment,
     vertical_alignment: LayoutAlignment,
@@ -152,6 +204,51 @@ where
     min_size: Size,
     gap: u32,
     max_size: Size,
+    clear_background: bool,
+    disabled: bool,
+    uniform: bool,
+}
+
+impl<'a, D, C> LinearLayout<'a, D, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    /// Returns the main axis length of the longest child in [LinearLayoutBuilder::uniform] mode,
+    /// `0` otherwise
+    fn uniform_length(&mut self, context: &mut UiContext<'a, D, C>, hint: Size) -> u32 {
+        if !self.uniform {
+            return 0;
+        }
+
+        let mut length = 0;
+        for child in self.children.iter_mut() {
+            if fills_main_axis(self.direction, child) {
+                continue;
+            }
+
+            let child_size = child.size(context, hint);
+            length = length.max(match self.direction {
+                LayoutDirection::Horizontal => child_size.width,
+                LayoutDirection::Vertical => child_size.height,
+            });
+        }
+        length
+    }
+}
+
+/// Returns `true` if the child takes the free space on the `direction` axis
+fn fills_main_axis<'a, D, C>(direction: LayoutDirection, child: &mut WidgetObject<'a, D, C>) -> bool
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    matches!(
+        (direction, child.widget.fill_strategy()),
+        (_, Some(FillStrategy::Both))
+            | (LayoutDirection::Horizontal, Some(FillStrategy::Horizontal))
+            | (LayoutDirection::Vertical, Some(FillStrategy::Vertical))
+    )
 }
 
 impl<'a, D, C> Widget<'a, D, C> for LinearLayout<'a, D, C>
@@ -162,28 +259,52 @@ where
     fn size(&mut self, context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
         let mut computed_size = Size::zero();
         let gap_total = self.gap * self.children.len().saturating_sub(1) as u32;
+        let uniform_length = self.uniform_length(context, hint);
 
-        for child in &mut self.children {
-            // oh dear...
-            let remaining_size = match self.direction {
-                LayoutDirection::Horizontal => {
-                    Size::new(hint.width.saturating_sub(computed_size.width), hint.height)
+        // fillers are measured last, they get the space left by the other children
+        for fillers in [false, true] {
+            for child in self.children.iter_mut() {
+                if fills_main_axis(self.direction, child) != fillers {
+                    continue;
                 }
-                LayoutDirection::Vertical => {
-                    Size::new(hint.width, hint.height.saturating_sub(computed_size.height))
-                }
-            };
-
-            let child_size = child.size(context, remaining_size);
 
-            match self.direction {
-                LayoutDirection::Horizontal => {
-                    computed_size.width += child_size.width + gap_total;
-                    computed_size.height = computed_size.height.max(child_size.height);
+                // oh dear...
+                let remaining_size = match self.direction {
+                    LayoutDirection::Horizontal => {
+                        Size::new(hint.width.saturating_sub(computed_size.width), hint.height)
+                    }
+                    LayoutDirection::Vertical => {
+                        Size::new(hint.width, hint.height.saturating_sub(computed_size.height))
+                    }
+                };
+
+                let mut child_size = child.size(context, remaining_size);
+                if !fillers {
+                    match self.direction {
+                        LayoutDirection::Horizontal => {
+                            child_size.width = child_size.width.max(uniform_length)
+                        }
+                        LayoutDirection::Vertical => {
+                            child_size.height = child_size.height.max(uniform_length)
+                        }
+                    }
                 }
-                LayoutDirection::Vertical => {
-                    computed_size.width = computed_size.width.max(child_size.width);
-                    computed_size.height += child_size.height + gap_total;
+
+                match self.direction {
+                    LayoutDirection::Horizontal => {
+                        computed_size.width = computed_size
+                            .width
+                            .saturating_add(child_size.width)
+                            .saturating_add(gap_total);
+                        computed_size.height = computed_size.height.max(child_size.height);
+                    }
+                    LayoutDirection::Vertical => {
+                        computed_size.width = computed_size.width.max(child_size.width);
+                        computed_size.height = computed_size
+                            .height
+                            .saturating_add(child_size.height)
+                            .saturating_add(gap_total);
+                    }
                 }
             }
         }
@@ -205,43 +326,61 @@ where
 
     fn layout(&mut self, context: &mut UiContext<'a, D, C>, rect: Rectangle) {
         let total_gap = self.gap * self.children.len().saturating_sub(1) as u32;
+        let uniform_length = self.uniform_length(context, rect.size);
+        // fillers take the free space, they do not count into the length
+        let mut filler_count: u32 = 0;
         let total_length = match self.direction {
             LayoutDirection::Horizontal => {
                 let mut total = 0;
                 for child in &mut self.children {
+                    if fills_main_axis(self.direction, child) {
+                        filler_count += 1;
+                        continue;
+                    }
                     let child_size =
                         child.size(context, Size::new(rect.size.width, rect.size.height));
-                    total += child_size.width;
+                    total = child_size.width.max(uniform_length).saturating_add(total);
                 }
                 total
             }
             LayoutDirection::Vertical => {
                 let mut total = 0;
                 for child in &mut self.children {
+                    if fills_main_axis(self.direction, child) {
+                        filler_count += 1;
+                        continue;
+                    }
                     let child_size =
                         child.size(context, Size::new(rect.size.width, rect.size.height));
-                    total += child_size.height;
+                    total = child_size.height.max(uniform_length).saturating_add(total);
                 }
                 total
             }
-        } + total_gap;
+        }
+        .saturating_add(total_gap);
 
         let main_axis_free_space = match self.direction {
             LayoutDirection::Horizontal => rect.size.width.saturating_sub(total_length),
             LayoutDirection::Vertical => rect.size.height.saturating_sub(total_length),
         };
 
-        let main_alignment = if self.direction == LayoutDirection::Horizontal {
+        let main_alignment = if filler_count > 0 {
+            // nothing left to align or stretch
+            LayoutAlignment::Start
+        } else if self.direction == LayoutDirection::Horizontal {
             self.horizontal_alignment
         } else {
             self.vertical_alignment
         };
+        let filler_share = main_axis_free_space / filler_count.max(1);
+        let mut filler_space = main_axis_free_space;
+        let mut fillers_left = filler_count;
 
-        let mut main_offset = match main_alignment {
+        let mut main_offset = to_coord(match main_alignment {
             LayoutAlignment::Center => main_axis_free_space / 2,
             LayoutAlignment::End => main_axis_free_space,
             _ => 0,
-        } as i32;
+        });
 
         let children_count = self.children.len();
 
@@ -259,6 +398,30 @@ where
             let child_bounds = Size::new(rect.size.width, rect.size.height);
             let mut child_size = child.size(context, child_bounds);
 
+            if fills_main_axis(self.direction, child) {
+                // the last filler gets the rounding remainder
+                fillers_left -= 1;
+                let share = if fillers_left == 0 {
+                    filler_space
+                } else {
+                    filler_share
+                };
+                filler_space -= share;
+                match self.direction {
+                    LayoutDirection::Horizontal => child_size.width = share,
+                    LayoutDirection::Vertical => child_size.height = share,
+                }
+            } else {
+                match self.direction {
+                    LayoutDirection::Horizontal => {
+                        child_size.width = child_size.width.max(uniform_length)
+                    }
+                    LayoutDirection::Vertical => {
+                        child_size.height = child_size.height.max(uniform_length)
+                    }
+                }
+            }
+
             let cross_alignment = if self.direction == LayoutDirection::Horizontal {
                 self.vertical_alignment
             } else {
@@ -286,7 +449,7 @@ where
                 }
             }
 
-            let cross_offset = match self.direction {
+            let cross_offset = to_coord(match self.direction {
                 LayoutDirection::Horizontal => {
                     let free_space = rect.size.height.saturating_sub(child_size.height);
                     match self.vertical_alignment {
@@ -303,20 +466,20 @@ where
                         _ => 0,
                     }
                 }
-            } as i32;
+            });
 
             let child_rect = match self.direction {
                 LayoutDirection::Horizontal => Rectangle::new(
                     Point::new(
-                        rect.top_left.x + main_offset,
-                        rect.top_left.y + cross_offset,
+                        rect.top_left.x.saturating_add(main_offset),
+                        rect.top_left.y.saturating_add(cross_offset),
                     ),
                     child_size,
                 ),
                 LayoutDirection::Vertical => Rectangle::new(
                     Point::new(
-                        rect.top_left.x + cross_offset,
-                        rect.top_left.y + main_offset,
+                        rect.top_left.x.saturating_add(cross_offset),
+                        rect.top_left.y.saturating_add(main_offset),
                     ),
                     child_size,
                 ),
@@ -327,15 +490,15 @@ where
 
             match self.direction {
                 LayoutDirection::Horizontal => {
-                    main_offset += child_size.width as i32;
+                    main_offset = main_offset.saturating_add(to_coord(child_size.width));
                     if i != children_count - 1 {
-                        main_offset += self.gap as i32;
+                        main_offset = main_offset.saturating_add(to_coord(self.gap));
                     }
                 }
                 LayoutDirection::Vertical => {
-                    main_offset += child_size.height as i32;
+                    main_offset = main_offset.saturating_add(to_coord(child_size.height));
                     if i != children_count - 1 {
-                        main_offset += self.gap as i32;
+                        main_offset = main_offset.saturating_add(to_coord(self.gap));
                     }
                 }
             }
@@ -348,20 +511,29 @@ where
         rect: Rectangle,
         event_args: WidgetEvent,
     ) -> EventResult {
-        let _ = rect
-            .into_styled(self.style.into())
-            .draw(&mut context.draw_target);
+        if self.clear_background && self.style.background_color.is_none() {
+            let color = context.background_color();
+            let _ = context.draw_target.fill_solid(&rect, color);
+        }
+        self.style
+            .draw_background(&mut context.draw_target, rect);
+
+        // children without a background are drawn on this one
+        let parent_background = context.background;
+        if self.style.background_color.is_some() {
+            context.background = self.style.background_color;
+        }
+        let parent_disabled = context.disabled;
+        context.disabled |= self.disabled;
 
         let mut event_result = EventResult::Pass;
 
         for child in self.children.iter_mut() {
-            if event_result == EventResult::Stop {
-                event_result = child.draw(context, &SystemEvent::Idle);
-            } else {
-                event_result = child.draw(context, event_args.system_event);
-            }
+            child.draw_sibling(context, event_args.system_event, &mut event_result);
         }
 
+        context.background = parent_background;
+        context.disabled = parent_disabled;
         event_result
     }
 }
@@ -369,8 +541,135 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::themes::hope_diamond::{self};
-    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb565};
+    use crate::{
+        testing::{framebuffer_context, mock_context, Block, Framebuffer, RectRecorder},
+        SystemEvent,
+        themes::{
+            hope_diamond::{self},
+            light, BorderStyle,
+        },
+        widgets::filler::{FillStrategy, Filler},
+    };
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        mono_font::ascii::FONT_4X6,
+        pixelcolor::{BinaryColor, Rgb565, Rgb888},
+    };
+
+    #[test]
+    fn shrinking_child_leaves_no_stale_pixels() {
+        let size = Size::new(32, 16);
+        let mut ctx = framebuffer_context(size);
+        let background = ctx.background_color();
+        let ui = |width| {
+            let mut ui = LinearLayoutBuilder::default().clear_background(true);
+            ui.add_widget(Block(Size::new(width, 8), Rgb888::RED));
+            ui.finish()
+        };
+
+        let wide = Rectangle::new(Point::zero(), Size::new(20, 8));
+        let narrow = Rectangle::new(Point::zero(), Size::new(10, 8));
+        let rest = Rectangle::new(Point::new(10, 0), Size::new(22, 16));
+
+        ctx.update(ui(20));
+        assert!(ctx.draw_target.is_filled(wide, Rgb888::RED));
+
+        ctx.update(ui(10));
+        assert!(ctx.draw_target.is_filled(narrow, Rgb888::RED));
+        assert!(ctx.draw_target.is_filled(rest, background));
+    }
+
+    #[test]
+    fn cleared_layout_uses_screen_background() {
+        let size = Size::new(16, 16);
+        let mut ctx = UiContext::new(Framebuffer::new(size, Rgb888::RED), light::apply());
+        let screen = ctx.theme.background_color;
+        assert_ne!(ctx.theme.button_style.idle.background_color, Some(screen));
+
+        ctx.update(
+            LinearLayoutBuilder::default()
+                .clear_background(true)
+                .finish(),
+        );
+
+        assert!(ctx
+            .draw_target
+            .is_filled(Rectangle::new(Point::zero(), size), screen));
+    }
+
+    #[test]
+    fn filler_pushes_widgets_apart() {
+        let size = Size::new(64, 8);
+
+        for alignment in [LayoutAlignment::Start, LayoutAlignment::Stretch] {
+            let framebuffer = Framebuffer::new(size, Rgb888::BLACK);
+            let mut ctx = UiContext::new(framebuffer, hope_diamond::apply());
+            let mut ui = LinearLayoutBuilder::default()
+                .direction(LayoutDirection::Horizontal)
+                .horizontal_alignment(alignment);
+            ui.add_widget(Block(Size::new(10, 8), Rgb888::RED));
+            ui.add_widget(Filler::new(FillStrategy::Horizontal));
+            ui.add_widget(Block(Size::new(10, 8), Rgb888::GREEN));
+            ctx.update(ui.finish());
+
+            let left = Rectangle::new(Point::zero(), Size::new(10, 8));
+            let right = Rectangle::new(Point::new(54, 0), Size::new(10, 8));
+            assert!(ctx.draw_target.is_filled(left, Rgb888::RED));
+            assert!(ctx.draw_target.is_filled(right, Rgb888::GREEN));
+        }
+    }
+
+    #[test]
+    fn uniform_buttons_get_widest_width() {
+        let mut ctx = framebuffer_context(Size::new(128, 20));
+        let mut ui = LinearLayoutBuilder::default()
+            .direction(LayoutDirection::Horizontal)
+            .gap(2)
+            .uniform(true);
+        ui.button("A", &FONT_4X6, || {});
+        ui.button("Cancel", &FONT_4X6, || {});
+        ui.button("OK", &FONT_4X6, || {});
+        ctx.update(ui.finish());
+
+        // widths of the button borders on the first row, "Cancel" is 24 + 2 * 6 pixels wide
+        let mut widths = alloc::vec::Vec::new();
+        let mut run = 0;
+        for x in 0..128 {
+            if ctx.draw_target.pixel(Point::new(x, 0)) == Some(Rgb888::BLACK) {
+                if run > 0 {
+                    widths.push(run);
+                }
+                run = 0;
+            } else {
+                run += 1;
+            }
+        }
+        assert_eq!(widths, [36, 36, 36]);
+    }
+
+    #[test]
+    fn huge_children_do_not_overflow() {
+        let last = core::cell::Cell::new(Rectangle::zero());
+        let mut ctx = mock_context::<Rgb565>();
+        let huge = Rectangle::new(Point::new(5, 5), Size::new(u32::MAX, u32::MAX));
+
+        for direction in [LayoutDirection::Horizontal, LayoutDirection::Vertical] {
+            let mut layout = LinearLayoutBuilder::default()
+                .direction(direction)
+                .horizontal_alignment(LayoutAlignment::End)
+                .vertical_alignment(LayoutAlignment::End);
+            layout.add_widget(Filler::new(FillStrategy::Both));
+            layout.add_widget(RectRecorder(&last));
+            let mut layout = layout.finish();
+
+            layout.size(&mut ctx, huge.size);
+            layout.layout(&mut ctx, huge);
+
+            // the offset saturates instead of wrapping to negative coordinates
+            let top_left = last.get().top_left;
+            assert!(top_left.x >= huge.top_left.x && top_left.y >= huge.top_left.y);
+        }
+    }
 
     #[test]
     fn linear_assume_zero_size() {
@@ -384,4 +683,123 @@ mod tests {
             assert_eq!(size, Size::zero());
         }
     }
+
+    #[test]
+    fn adding_beyond_capacity_drops_widget() {
+        let mut ui = LinearLayoutBuilder::<MockDisplay<Rgb565>, Rgb565>::default().capacity(2);
+        let storage = ui.children.as_ptr();
+
+        for _ in 0..3 {
+            ui.add_widget(Filler::new(FillStrategy::Both));
+        }
+
+        assert!(ui.is_full());
+        assert_eq!(ui.children.len(), 2);
+        assert_eq!(ui.children.as_ptr(), storage);
+    }
+
+    /// Interactive widget counting how many times it was asked for an id
+    struct IdRequests<'a>(&'a core::cell::Cell<u32>);
+
+    impl<'a, D, C> Widget<'a, D, C> for IdRequests<'a>
+    where
+        D: DrawTarget<Color = C>,
+        C: PixelColor,
+    {
+        fn is_interactive(&mut self) -> bool {
+            self.0.set(self.0.get() + 1);
+            true
+        }
+    }
+
+    #[test]
+    fn full_layout_hands_out_no_ids() {
+        let requests = core::cell::Cell::new(0);
+        let mut ctx = mock_context::<Rgb565>();
+        let mut ui = LinearLayoutBuilder::default().capacity(1);
+
+        assert_ne!(
+            ui.add_widget_returning_id(&mut ctx, IdRequests(&requests)),
+            0
+        );
+        assert_eq!(
+            ui.add_widget_returning_id(&mut ctx, IdRequests(&requests)),
+            0
+        );
+        ui.add_widget(IdRequests(&requests));
+
+        assert_eq!(requests.get(), 1);
+        assert_eq!(ui.children.len(), 1);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn heapless_layout_drops_widgets_beyond_slots() {
+        use crate::widgets::MAX_CHILDREN;
+
+        let mut ui = LinearLayoutBuilder::<MockDisplay<Rgb565>, Rgb565>::default();
+
+        for _ in 0..MAX_CHILDREN + 1 {
+            ui.add_widget(Filler::new(FillStrategy::Both));
+        }
+
+        assert!(ui.is_full());
+        assert_eq!(ui.children.len(), MAX_CHILDREN);
+    }
+
+    #[test]
+    fn top_border_only() {
+        let mut ctx = mock_context::<Rgb565>();
+        let style = WidgetStyle::default().border(BorderStyle::new().top(2, Rgb565::RED));
+        let rect = Rectangle::new(Point::new(4, 4), Size::new(20, 10));
+
+        let mut layout = LinearLayoutBuilder::default().style(style).finish();
+        layout.layout(&mut ctx, rect);
+        layout.draw(&mut ctx, &SystemEvent::Idle);
+
+        let red = Some(Rgb565::RED);
+        assert_eq!(ctx.draw_target.get_pixel(Point::new(10, 4)), red);
+        assert_eq!(ctx.draw_target.get_pixel(Point::new(10, 5)), red);
+        assert_ne!(ctx.draw_target.get_pixel(Point::new(10, 6)), red);
+        assert_ne!(ctx.draw_target.get_pixel(Point::new(10, 13)), red);
+        assert_ne!(ctx.draw_target.get_pixel(Point::new(4, 9)), red);
+        assert_ne!(ctx.draw_target.get_pixel(Point::new(23, 9)), red);
+    }
+
+    #[test]
+    fn shadow_drawn_at_offset_corner() {
+        let mut ctx = mock_context::<Rgb565>();
+        let style = WidgetStyle::default()
+            .background_color(Rgb565::WHITE)
+            .shadow(2, 2, Rgb565::BLACK);
+        let rect = Rectangle::new(Point::new(4, 4), Size::new(20, 10));
+
+        let mut layout = LinearLayoutBuilder::default().style(style).finish();
+        layout.layout(&mut ctx, rect);
+        layout.draw(&mut ctx, &SystemEvent::Idle);
+
+        let bottom_right = rect.bottom_right().unwrap();
+        assert_eq!(ctx.draw_target.get_pixel(bottom_right), Some(Rgb565::WHITE));
+        assert_eq!(
+            ctx.draw_target.get_pixel(bottom_right + Point::new(2, 2)),
+            Some(Rgb565::BLACK)
+        );
+        assert_eq!(
+            ctx.draw_target.get_pixel(rect.top_left - Point::new(1, 1)),
+            None
+        );
+    }
+
+    #[test]
+    fn shadow_skipped_on_monochrome() {
+        let mut ctx = mock_context::<BinaryColor>();
+        let style = WidgetStyle::default().shadow(2, 2, BinaryColor::On);
+        let rect = Rectangle::new(Point::new(4, 4), Size::new(20, 10));
+
+        let mut layout = LinearLayoutBuilder::default().style(style).finish();
+        layout.layout(&mut ctx, rect);
+        layout.draw(&mut ctx, &SystemEvent::Idle);
+
+        assert_eq!(ctx.draw_target.get_pixel(Point::new(25, 15)), None);
+    }
 }
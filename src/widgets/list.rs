@@ -0,0 +1,314 @@
+use alloc::boxed::Box;
+use embedded_graphics::{
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+};
+
+use super::{Widget, WidgetEvent, WidgetObject};
+use crate::{Event, EventResult, SystemEvent, UiContext};
+
+type RowBuilder<'a, T, D, C> = Box<dyn FnMut(usize, &T) -> WidgetObject<'a, D, C> + 'a>;
+
+/// Virtualized list widget. Builds and draws only the rows visible from the `scroll` offset, so
+/// long lists cost the same as short ones. Rows are built by a closure for each visible item
+pub struct ListView<'a, T, D, C>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    items: &'a [T],
+    /// Index of the first visible item
+    pub scroll: usize,
+    /// Currently selected item
+    pub selected: Option<usize>,
+    /// Fixed row height, `None` measures the first visible row and uses it for every row
+    pub row_height: Option<u32>,
+    render: RowBuilder<'a, T, D, C>,
+    callback: Box<dyn FnMut(usize) + 'a>,
+}
+
+impl<'a, T, D, C> ListView<'a, T, D, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    /// Creates a list over `items`. `render` builds a row widget for an item, `callback` receives
+    /// the index of a newly selected item
+    pub fn new(
+        items: &'a [T],
+        render: impl FnMut(usize, &T) -> WidgetObject<'a, D, C> + 'a,
+        callback: impl FnMut(usize) + 'a,
+    ) -> Self {
+        Self {
+            items,
+            scroll: 0,
+            selected: None,
+            row_height: None,
+            render: Box::new(render),
+            callback: Box::new(callback),
+        }
+    }
+
+    /// Returns the configured row height, or measures the first visible row without storing it
+    /// so [ListView::row_height] stays as configured
+    fn row_height(&mut self, context: &mut UiContext<'a, D, C>, width: u32) -> u32 {
+        if let Some(height) = self.row_height {
+            return height.max(1);
+        }
+
+        match self.items.get(self.scroll) {
+            Some(item) => {
+                (self.render)(self.scroll, item)
+                    .size(context, Size::new(width, u32::MAX))
+                    .height
+            }
+            None => 0,
+        }
+        .max(1)
+    }
+
+    fn select(&mut self, index: usize) {
+        if index < self.items.len() && self.selected != Some(index) {
+            self.selected = Some(index);
+            (self.callback)(index);
+        }
+    }
+
+    /// Scrolls the minimal amount so the selected item is visible
+    fn scroll_to_selected(&mut self, visible_rows: usize) {
+        if let Some(selected) = self.selected {
+            if selected < self.scroll {
+                self.scroll = selected;
+            } else if selected >= self.scroll + visible_rows {
+                self.scroll = selected + 1 - visible_rows;
+            }
+        }
+    }
+}
+
+impl<'a, T, D, C> Widget<'a, D, C> for ListView<'a, T, D, C>
+where
+    T: 'a,
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn is_interactive(&mut self) -> bool {
+        true
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        event_args: WidgetEvent,
+    ) -> EventResult {
+        let row_height = self.row_height(context, rect.size.width);
+        let visible_rows = ((rect.size.height / row_height) as usize).max(1);
+
+        let mut event_result = EventResult::Pass;
+
+        if event_args.is_focused {
+            match event_args.system_event {
+                SystemEvent::Increase(_) => {
+                    self.select(self.selected.map_or(0, |selected| selected + 1));
+                    event_result = EventResult::Stop;
+                }
+                SystemEvent::Decrease(_) => {
+                    self.select(
+                        self.selected
+                            .map_or(0, |selected| selected.saturating_sub(1)),
+                    );
+                    event_result = EventResult::Stop;
+                }
+                _ => {}
+            }
+        }
+
+        if let Event::Active(Some(position)) = event_args.event {
+            context.focused_element = event_args.id;
+            let row = (position.y.max(0) as u32 / row_height) as usize;
+            // clicks below the last visible row select nothing
+            if row < visible_rows {
+                self.select(self.scroll.saturating_add(row));
+            }
+            event_result = EventResult::Stop;
+        }
+
+        self.scroll_to_selected(visible_rows);
+
+        let highlight = context
+            .style(&context.theme.button_style, &Event::Focus)
+            .background_color;
+
+        let end = (self.scroll + visible_rows).min(self.items.len());
+        for index in self.scroll..end {
+            let row_rect = Rectangle::new(
+                Point::new(
+                    rect.top_left.x,
+                    rect.top_left.y + ((index - self.scroll) as u32 * row_height) as i32,
+                ),
+                Size::new(rect.size.width, row_height),
+            );
+
+            if self.selected == Some(index) {
+                if let Some(color) = highlight {
+                    let _ = row_rect
+                        .into_styled(PrimitiveStyle::with_fill(color))
+                        .draw(&mut context.draw_target);
+                }
+            }
+
+            let mut row = (self.render)(index, &self.items[index]);
+            row.size(context, row_rect.size);
+            row.layout(context, row_rect);
+            // rows are built without ids, so focusing the row id styles the whole selected row
+            let row_event = if self.selected == Some(index) {
+                SystemEvent::FocusTo(row.id())
+            } else {
+                SystemEvent::Idle
+            };
+            row.draw(context, &row_event);
+        }
+
+        event_result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::mock_context,
+        widgets::{
+            filler::{FillStrategy, Filler},
+            label::{Label, LabelOptions},
+        },
+    };
+    use alloc::vec::Vec;
+    use core::cell::{Cell, RefCell};
+    use embedded_graphics::{mono_font::ascii::FONT_4X6, pixelcolor::Rgb888};
+
+    #[test]
+    fn only_visible_rows_are_built() {
+        let items: Vec<u32> = (0..1000).collect();
+        let built = RefCell::new(Vec::new());
+        let mut ctx = mock_context::<Rgb888>();
+
+        let mut list = ListView::new(
+            &items,
+            |index, _| {
+                built.borrow_mut().push(index);
+                WidgetObject::new(Box::new(Filler::new(FillStrategy::Both)))
+            },
+            |_| {},
+        );
+        list.row_height = Some(10);
+        list.scroll = 500;
+
+        list.draw(
+            &mut ctx,
+            Rectangle::new(Point::zero(), Size::new(64, 60)),
+            WidgetEvent::default(),
+        );
+
+        assert_eq!(*built.borrow(), (500..506).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn click_selects_row() {
+        let items = [1, 2, 3, 4];
+        let selected = Cell::new(None);
+        let mut ctx = mock_context::<Rgb888>();
+
+        let mut list = ListView::new(
+            &items,
+            |_, _| WidgetObject::new(Box::new(Filler::new(FillStrategy::Both))),
+            |index| selected.set(Some(index)),
+        );
+        list.row_height = Some(10);
+
+        list.draw(
+            &mut ctx,
+            Rectangle::new(Point::zero(), Size::new(64, 40)),
+            WidgetEvent {
+                event: &Event::Active(Some(Point::new(5, 25))),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(selected.get(), Some(2));
+        assert_eq!(list.selected, Some(2));
+
+        // the row index past the last item neither overflows nor selects
+        for (scroll, y) in [(usize::MAX - 1, 35), (0, i32::MAX)] {
+            list.scroll = scroll;
+            list.draw(
+                &mut ctx,
+                Rectangle::new(Point::zero(), Size::new(64, 40)),
+                WidgetEvent {
+                    event: &Event::Active(Some(Point::new(5, y))),
+                    ..Default::default()
+                },
+            );
+            assert_eq!(list.selected, Some(2));
+        }
+    }
+
+    #[test]
+    fn measured_row_height_is_not_stored() {
+        let items = [1, 2, 3];
+        let mut ctx = mock_context::<Rgb888>();
+        let mut list = ListView::new(
+            &items,
+            |_, _| WidgetObject::new(Box::new(Label::new("row", LabelOptions::new(), &FONT_4X6))),
+            |_| {},
+        );
+
+        list.draw(
+            &mut ctx,
+            Rectangle::new(Point::zero(), Size::new(64, 40)),
+            WidgetEvent::default(),
+        );
+        assert_eq!(list.row_height, None);
+    }
+
+    #[test]
+    fn selected_row_drawn_focused() {
+        let items = [1, 2];
+        let events = RefCell::new(Vec::new());
+        let mut ctx = mock_context::<Rgb888>();
+        let mut list = ListView::new(
+            &items,
+            |_, _| WidgetObject::new(Box::new(EventLog(&events))),
+            |_| {},
+        );
+        list.row_height = Some(10);
+        list.selected = Some(1);
+
+        list.draw(
+            &mut ctx,
+            Rectangle::new(Point::zero(), Size::new(64, 40)),
+            WidgetEvent::default(),
+        );
+        assert_eq!(*events.borrow(), [Event::Idle, Event::Focus]);
+    }
+
+    /// Records the event of every draw
+    struct EventLog<'e>(&'e RefCell<Vec<Event>>);
+
+    impl<'a, D, C> Widget<'a, D, C> for EventLog<'a>
+    where
+        D: DrawTarget<Color = C> + 'a,
+        C: PixelColor + 'a,
+    {
+        fn draw(
+            &mut self,
+            _context: &mut UiContext<'a, D, C>,
+            _rect: Rectangle,
+            event_args: WidgetEvent,
+        ) -> EventResult {
+            self.0.borrow_mut().push(*event_args.event);
+            EventResult::Pass
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{
     margin,
-    themes::DynamicStyle,
+    themes::{DynamicStyle, WidgetStyle},
     widgets::{margin_layout::Margin, WidgetEvent},
-    Event, EventResult, UiContext,
+    EventResult, UiContext,
 };
 
 use super::Widget;
@@ -49,12 +49,10 @@ impl<'a, C: PixelColor> MenuEntryStyle<'a, C> {
         }
     }
 
-    fn get_font_style(&self, event: &Event) -> MonoTextStyle<'a, C> {
+    fn get_font_style(&self, style: &WidgetStyle<C>) -> MonoTextStyle<'a, C> {
         MonoTextStyle::new(
             self.font,
-            self.style
-                .expect("No style was set")
-                .style(event)
+            style
                 .foreground_color
                 .expect("Foreground color is needed for drawing menu entry!"),
         )
@@ -101,17 +99,20 @@ where
         event_args: WidgetEvent,
     ) -> EventResult {
         let mut y_offset = 0;
+        let entry_style = context.style(
+            &self.style.style.expect("No style was set"),
+            event_args.event,
+        );
         for entry in self.entries.iter() {
             let text_height = self
                 .style
-                .get_font_style(event_args.event)
+                .get_font_style(&entry_style)
                 .measure_string(entry.as_ref(), rect.top_left, Baseline::Top)
                 .bounding_box
                 .size
                 .height;
 
-            let mut style: PrimitiveStyle<C> =
-                self.style.style.unwrap().style(event_args.event).into();
+            let mut style: PrimitiveStyle<C> = entry_style.into();
 
             style.stroke_alignment = StrokeAlignment::Inside;
 
@@ -127,7 +128,7 @@ where
                     rect_background.top_left.x + style.stroke_width as i32,
                     rect_background.center().y + style.stroke_width as i32,
                 ),
-                self.style.get_font_style(event_args.event),
+                self.style.get_font_style(&entry_style),
             )
             .draw(&mut context.draw_target);
 
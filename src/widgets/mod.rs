@@ -5,9 +5,10 @@
 //! `Layout` - A container(-like) widget that holds another widgets
 use alloc::{boxed::Box, format, string::String, vec::Vec};
 use button::Button;
+use center::Center;
 use eg_seven_segment::SevenSegmentStyle;
 use embedded_graphics::{
-    mono_font::{iso_8859_16::FONT_4X6, MonoFont, MonoTextStyle},
+    mono_font::{MonoFont, MonoTextStyle},
     prelude::*,
     primitives::{PrimitiveStyle, PrimitiveStyleBuilder, Rectangle},
     text::{Alignment, Text},
@@ -15,33 +16,52 @@ use embedded_graphics::{
 use filler::{FillStrategy, Filler};
 use gauge::{Gauge, GaugeStyle};
 use grid_layout::GridLayoutBuilder;
+use histogram::Histogram;
 use image::Image;
 use label::{Label, SevenSegmentWidget};
 use linear_layout::{LayoutAlignment, LayoutDirection, LinearLayoutBuilder};
 use margin_layout::{Margin, MarginLayout};
 use plot::Plot;
-use primitive::Primitive;
+use primitive::{Primitive, SizedPrimitive};
+use ring_progress::RingProgress;
 use slider::Slider;
+use stack::Stack;
+use stepper::Stepper;
+use switch::{Switch, SwitchState};
+use theme_scope::ThemeScope;
 use toggle_button::ToggleButton;
 
-use crate::{widgets::{label::LabelOptions}, Event, EventResult, SystemEvent, UiContext};
+use crate::{themes::Theme, widgets::{label::LabelOptions}, Event, EventResult, SystemEvent, UiContext};
 
 pub mod alert;
 pub mod button;
+pub mod center;
+pub mod color_picker;
 pub mod debug;
+pub mod dropdown;
 pub mod filler;
 pub mod gauge;
 pub mod grid_layout;
+pub mod histogram;
+pub mod icon;
 pub mod image;
+pub mod jog_dial;
 pub mod label;
 pub mod linear_layout;
+pub mod list;
 pub mod margin_layout;
 pub mod plot;
 pub mod primitive;
+pub mod ring_progress;
 pub mod root_layout;
 pub mod slider;
+pub mod stack;
+pub mod stepper;
+pub mod switch;
+pub mod theme_scope;
 pub mod battery;
 pub mod toggle_button;
+pub mod waterfall;
 pub mod menu;
 
 /// Widget event arguments
@@ -64,6 +84,151 @@ impl<'a> Default for WidgetEvent<'a> {
     }
 }
 
+impl<'a> WidgetEvent<'a> {
+    /// Returns the visual state of the widget for this event. The mapping is:
+    ///
+    /// | [Event]          | [Modifier]         |
+    /// |------------------|--------------------|
+    /// | `Event::Idle`    | `Modifier::None`   |
+    /// | `Event::Focus`   | `Modifier::Focus`  |
+    /// | `Event::Active`  | `Modifier::Active` |
+    /// | `Event::Drag`    | `Modifier::Drag`   |
+    pub fn get_modifier(&self) -> Modifier {
+        Modifier::from(self.event)
+    }
+}
+
+/// Visual state of the widget, selects the style from [crate::themes::DynamicStyle]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Modifier {
+    None,
+    Focus,
+    Active,
+    Drag,
+    /// Inside a disabled layout, see [crate::UiContext::modifier]
+    Disabled,
+}
+
+impl From<&Event> for Modifier {
+    fn from(event: &Event) -> Self {
+        match event {
+            Event::Idle => Modifier::None,
+            Event::Focus => Modifier::Focus,
+            Event::Active(_) => Modifier::Active,
+            Event::Drag(_) => Modifier::Drag,
+        }
+    }
+}
+
+/// Sanitizes a widget value in range 0.0-1.0 coming from the user: `NaN` becomes `0.0` and
+/// infinities are clamped to the range, finite values are kept as is
+pub(crate) fn finite_value(value: f32) -> f32 {
+    if value.is_nan() {
+        0.0
+    } else if value.is_infinite() {
+        value.clamp(0.0, 1.0)
+    } else {
+        value
+    }
+}
+
+/// Routes an event among children stacked on top of each other and drawn in order (e.g. [Stack]):
+/// a pointer event goes only to the last (topmost) child whose rect contains the pointer, other
+/// events go to every child
+#[derive(Clone, Copy)]
+pub(crate) struct StackedTarget(Option<Option<usize>>);
+
+impl StackedTarget {
+    /// Finds the target of `event` among the children with `rects`, in drawing order
+    pub(crate) fn new(rects: impl Iterator<Item = Rectangle>, event: &SystemEvent) -> Self {
+        Self(event.point().map(|point| {
+            rects
+                .enumerate()
+                .filter(|(_, rect)| rect.contains(point))
+                .map(|(index, _)| index)
+                .last()
+        }))
+    }
+
+    /// Returns `true` if the child at `index` gets the event
+    pub(crate) fn includes(self, index: usize) -> bool {
+        match self.0 {
+            Some(target) => target == Some(index),
+            None => true,
+        }
+    }
+}
+
+/// Converts a size to a coordinate offset, sizes beyond `i32::MAX` (e.g. [MAX_SIZE]) saturate
+/// instead of wrapping to negative coordinates
+pub(crate) fn to_coord(value: u32) -> i32 {
+    value.min(i32::MAX as u32) as i32
+}
+
+/// Children of the linear and grid layouts. A growable [Vec] by default, with the `heapless`
+/// feature a fixed array of [MAX_CHILDREN] slots stored in the layout, which never allocates.
+///
+/// The layout builders take an optional `capacity` (e.g. [LinearLayoutBuilder::capacity]) for
+/// deterministic memory usage on microcontrollers: without `heapless` the storage for `capacity`
+/// children is allocated once up front and never grows. Widgets added to a full layout (see
+/// [UiBuilder::is_full]) are dropped before they get an id
+#[cfg(not(feature = "heapless"))]
+pub type Children<'a, D, C> = Vec<WidgetObject<'a, D, C>>;
+
+/// Children of the linear and grid layouts, see the default (non `heapless`) variant
+#[cfg(feature = "heapless")]
+pub type Children<'a, D, C> = heapless::Vec<WidgetObject<'a, D, C>, MAX_CHILDREN>;
+
+/// Children slots of every linear and grid layout with the `heapless` feature
+#[cfg(feature = "heapless")]
+pub const MAX_CHILDREN: usize = 16;
+
+/// Reserves the storage for `capacity` children up front, see [Children]
+pub(crate) fn reserve_children<D, C>(children: &mut Children<'_, D, C>, capacity: usize)
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    #[cfg(not(feature = "heapless"))]
+    children.reserve_exact(capacity.saturating_sub(children.len()));
+    // heapless storage is allocated with the layout
+    #[cfg(feature = "heapless")]
+    let _ = (children, capacity);
+}
+
+/// Returns `true` if `children` reached the layout `capacity` or the heapless slots
+pub(crate) fn children_full<D, C>(children: &Children<'_, D, C>, capacity: Option<usize>) -> bool
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    #[cfg(feature = "heapless")]
+    if children.is_full() {
+        return true;
+    }
+
+    capacity.is_some_and(|capacity| children.len() >= capacity)
+}
+
+/// Adds `widget` to `children`, drops it if they are full (see [children_full])
+pub(crate) fn push_child<'a, D, C>(
+    children: &mut Children<'a, D, C>,
+    capacity: Option<usize>,
+    widget: WidgetObject<'a, D, C>,
+) where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    if children_full(children, capacity) {
+        return;
+    }
+
+    #[cfg(not(feature = "heapless"))]
+    children.push(widget);
+    #[cfg(feature = "heapless")]
+    let _ = children.push(widget);
+}
+
 /// Trait for any widgets including containers
 /// Can also used as object
 #[allow(unused_variables)]
@@ -95,6 +260,12 @@ where
         Size::new(u32::MAX, u32::MAX)
     }
 
+    /// Axes on which the widget takes the free space of a [linear_layout::LinearLayout], before
+    /// it is distributed by the alignment. `None` for regular widgets, see [Filler]
+    fn fill_strategy(&mut self) -> Option<FillStrategy> {
+        None
+    }
+
     /// Widget drawing logic
     fn draw(
         &mut self,
@@ -114,8 +285,12 @@ where
 {
     pub(crate) widget: Box<dyn Widget<'a, D, C>>,
     pub(crate) computed_rect: Rectangle,
-    requested_size: Size,
+    /// Measured size and the frame it was measured in
+    requested_size: Option<(u32, Size)>,
     pub(crate) id: usize,
+    /// The widget takes an id when it is measured or drawn, see [WidgetObject::assign_id]
+    wants_id: bool,
+    overflow: bool,
 }
 
 impl<'a, D, C> WidgetObject<'a, D, C>
@@ -126,9 +301,11 @@ where
     pub fn new(widget: Box<dyn Widget<'a, D, C>>) -> Self {
         Self {
             computed_rect: Rectangle::default(),
-            requested_size: Size::default(),
+            requested_size: None,
             widget,
             id: 0,
+            wants_id: false,
+            overflow: false,
         }
     }
 }
@@ -138,22 +315,51 @@ where
     D: DrawTarget<Color = C> + 'a,
     C: PixelColor + 'a,
 {
-    /// Gets a size for widget (for layout compulation)
+    /// Gets a size for widget (for layout compulation). This is the measure pass: the widget is
+    /// measured only once per frame, later calls (e.g. from the parent's layout pass) return the
+    /// cached size and ignore the `hint`
     pub fn size(&mut self, context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
-        if self.requested_size == Size::zero() {
-            self.requested_size = self.widget.size(context, hint);
+        self.take_id(context);
+        let frame = context.frame_counter();
+        match self.requested_size {
+            Some((measured_frame, size)) if measured_frame == frame => size,
+            _ => {
+                if !context.enter_widget() {
+                    return Size::zero();
+                }
+                let size = self.widget.size(context, hint);
+                context.leave_widget();
+                self.requested_size = Some((frame, size));
+                size
+            }
         }
+    }
+
+    /// Drops the cached size, so the widget is measured again on the next [WidgetObject::size]
+    /// call. Retained widgets call this when the content changes within a frame
+    pub fn invalidate(&mut self) {
+        self.requested_size = None;
+    }
 
-        self.requested_size
+    /// Requests an id for an interactive widget, it takes the next free id of the context when
+    /// it is first measured or drawn. Widgets added with [UiBuilder::add_widget] request it
+    /// automatically, call it for objects created with [WidgetObject::new]. An already assigned
+    /// id is kept, see [UiContext::set_retained_ids]
+    pub fn assign_id(&mut self) {
+        self.wants_id = self.id == 0 && self.widget.is_interactive();
     }
 
-    fn assign_id(&mut self) {
-        if self.widget.is_interactive() {
-            // Portability: Using store + load instead of `fetch_add` because of some microcontrollers, for example - RP2040 does not support fetch_add
-            let id = crate::WIDGET_IDS.load(core::sync::atomic::Ordering::Relaxed) + 1;
-            self.id = id;
-            crate::WIDGET_IDS.store(id, core::sync::atomic::Ordering::Relaxed);
-        } 
+    /// Gives the widget the id requested with [WidgetObject::assign_id]
+    fn take_id(&mut self, context: &mut UiContext<'a, D, C>) {
+        if self.wants_id {
+            self.id = context.next_widget_id();
+            self.wants_id = false;
+        }
+    }
+
+    /// Returns the widget id, `0` for non-interactive widgets or before the widget is measured
+    pub fn id(&self) -> usize {
+        self.id
     }
 
     /// Returns a minimum size of widget
@@ -171,10 +377,19 @@ where
         self.computed_rect
     }
 
+    /// Returns `true` if the widget drew outside of its rectangle in the last frame. Tracked only
+    /// in debug mode with a context created by [UiContext::new_overflow_checked]
+    pub fn is_overflowing(&self) -> bool {
+        self.overflow
+    }
+
     /// Calls at layout pass. Gives a try for layout computation in Layouts (Containers)
     pub fn layout(&mut self, context: &mut UiContext<'a, D, C>, rect: Rectangle) {
         self.computed_rect = Rectangle::new(rect.top_left, self.calculate_bound_sizes(rect.size));
-        self.widget.layout(context, rect);
+        if context.enter_widget() {
+            self.widget.layout(context, rect);
+            context.leave_widget();
+        }
     }
 
     fn calculate_bound_sizes(&mut self, size: Size) -> Size {
@@ -238,19 +453,114 @@ where
         context: &mut UiContext<'a, D, C>,
         system_event: &SystemEvent,
     ) -> EventResult {
-        let event = self.handle_event(system_event);
+        self.take_id(context);
+        if !context.enter_widget() {
+            if context.debug_options.borrow().enabled {
+                let debug_style = context.theme.debug_style;
+                let _ = embedded_graphics::prelude::Primitive::into_styled(
+                    self.rect(),
+                    PrimitiveStyle::with_stroke(
+                        debug_style.debug_rect_overflow,
+                        debug_style.line_width,
+                    ),
+                )
+                .draw(&mut context.draw_target);
+            }
+            return EventResult::Pass;
+        }
+
+        let event_result = self.draw_widget(context, system_event);
+        context.leave_widget();
+        event_result
+    }
+
+    /// Draws the widget as a container child, following the propagation policy (see
+    /// [EventResult]): gets [SystemEvent::Idle] if an earlier sibling stopped the event, `result`
+    /// accumulates the siblings result
+    pub(crate) fn draw_sibling(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        system_event: &SystemEvent,
+        result: &mut EventResult,
+    ) {
+        let event = if *result == EventResult::Stop {
+            &SystemEvent::Idle
+        } else {
+            system_event
+        };
+
+        if self.draw(context, event) == EventResult::Stop {
+            *result = EventResult::Stop;
+        }
+    }
+
+    fn draw_widget(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        system_event: &SystemEvent,
+    ) -> EventResult {
+        // widgets inside a disabled layout neither react nor show focus
+        let system_event = if context.disabled {
+            &SystemEvent::Idle
+        } else {
+            system_event
+        };
+        let mut event = if context.disabled {
+            Event::Idle
+        } else {
+            self.handle_event(system_event)
+        };
+        // widgets missed by the interaction of this frame are styled by the motion pushed along
+        // with it, the system event stays the interaction
+        if event == Event::Idle && !context.disabled {
+            event = self.handle_event(&context.hover_event);
+        }
         let event_args = WidgetEvent {
             system_event,
-            is_focused: context.focused_element == self.id,
+            is_focused: !context.disabled && context.focused_element == self.id,
             id: self.id,
             event: &event,
         };
 
+        if self.id > 0 && event_args.is_focused {
+            context.focused_rect = Some(self.computed_rect);
+        }
+
+        if self.id > 0 && !context.disabled {
+            context.interactive.push((self.id, self.computed_rect));
+        }
+
+        let tracker = context
+            .overflow_tracker
+            .clone()
+            .filter(|_| context.debug_options.borrow().enabled);
+        let parent = tracker
+            .as_ref()
+            .map(|tracker| tracker.borrow_mut().enter(self.computed_rect));
+
         let event_result = self.widget.draw(context, self.rect(), event_args);
 
+        if let (Some(tracker), Some(parent)) = (tracker, parent) {
+            self.overflow = tracker.borrow_mut().leave(parent);
+        }
+
         let dbg = context.debug_options.borrow();
         if dbg.enabled {
-            let text = MonoTextStyle::new(&FONT_4X6, context.theme.label_color);
+            let debug_style = context.theme.debug_style;
+            if self.overflow {
+                let _ = embedded_graphics::prelude::Primitive::into_styled(
+                    self.rect(),
+                    PrimitiveStyleBuilder::new()
+                        .stroke_color(debug_style.debug_rect_overflow)
+                        .stroke_width(debug_style.line_width)
+                        .build(),
+                )
+                .draw(&mut context.draw_target);
+            }
+
+            let text = MonoTextStyle::new(debug_style.font, debug_style.label_color);
+            // one line below the top edge
+            let text_offset = debug_style.font.character_size.height as i32;
 
             if dbg.widget_ids {
                 if self.id > 0 {
@@ -258,7 +568,7 @@ where
                         &format!("id: {}", self.id),
                         Point::new(
                             self.computed_rect.top_left.x,
-                            self.computed_rect.top_left.y + 6,
+                            self.computed_rect.top_left.y + text_offset,
                         ),
                         text,
                     )
@@ -267,7 +577,7 @@ where
             }
 
             if dbg.widget_sizes {
-                let text = MonoTextStyle::new(&FONT_4X6, context.theme.debug_rect_active);
+                let text = MonoTextStyle::new(debug_style.font, debug_style.debug_rect_active);
                 let _ = Text::new(
                     &format!(
                         "{}x{}",
@@ -275,7 +585,7 @@ where
                     ),
                     Point::new(
                         self.computed_rect.top_left.x,
-                        self.computed_rect.top_left.y + 6,
+                        self.computed_rect.top_left.y + text_offset,
                     ),
                     text,
                 )
@@ -286,8 +596,8 @@ where
                 let _ = embedded_graphics::prelude::Primitive::into_styled(
                     self.rect(),
                     PrimitiveStyleBuilder::new()
-                        .stroke_color(context.theme.debug_rect)
-                        .stroke_width(1)
+                        .stroke_color(debug_style.debug_rect)
+                        .stroke_width(debug_style.line_width)
                         .build(),
                 )
                 .draw(&mut context.draw_target);
@@ -297,8 +607,8 @@ where
                 let _ = embedded_graphics::prelude::Primitive::into_styled(
                     self.rect(),
                     PrimitiveStyleBuilder::new()
-                        .stroke_color(context.theme.debug_rect_active)
-                        .stroke_width(1)
+                        .stroke_color(debug_style.debug_rect_active)
+                        .stroke_width(debug_style.line_width)
                         .build(),
                 )
                 .draw(&mut context.draw_target);
@@ -320,13 +630,45 @@ where
     /// Method for adding widget in Layouts
     fn add_widget_obj(&mut self, widget: WidgetObject<'a, D, C>);
 
+    /// Returns `true` if the layout can not take more children. Widgets added then are dropped
+    /// without getting an id, see [Children]
+    fn is_full(&self) -> bool {
+        false
+    }
+
     /// Adds a widget in current layout
     fn add_widget<W: Widget<'a, D, C>>(&mut self, widget: W) {
+        if self.is_full() {
+            return;
+        }
+
         let mut object = WidgetObject::new(Box::new(widget));
         object.assign_id();
         self.add_widget_obj(object);
     }
 
+    /// Adds a widget in current layout and returns its id, e.g. for focusing it later with
+    /// [SystemEvent::FocusTo]. The id is taken from the `context` the UI is drawn with right away,
+    /// other widgets take theirs when they are measured. Non-interactive widgets and widgets
+    /// dropped by a full layout get id `0`
+    fn add_widget_returning_id<W: Widget<'a, D, C>>(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        widget: W,
+    ) -> usize {
+        if self.is_full() {
+            return 0;
+        }
+
+        let mut object = WidgetObject::new(Box::new(widget));
+        if object.widget.is_interactive() {
+            object.id = context.next_widget_id();
+        }
+        let id = object.id;
+        self.add_widget_obj(object);
+        id
+    }
+
     /// Creates a [Label] widget
     fn label<S: Into<String>>(&mut self, text: S, text_alignment: Alignment, font: &'a MonoFont) {
         self.add_widget(Label::new(
@@ -377,6 +719,11 @@ where
         ));
     }
 
+    /// Shorthand for the sliding [Switch] widget
+    fn switch(&mut self, state: &'a SwitchState, callback: impl FnMut(bool) + 'a) {
+        self.add_widget(Switch::new(state, Box::new(callback)));
+    }
+
     /// Construct a [MarginLayout] widget
     fn margin_layout(&mut self, margin: Margin, fill: impl FnOnce(&mut MarginLayout<'a, D, C>)) {
         let mut builder = MarginLayout {
@@ -404,6 +751,21 @@ where
         self.add_widget_obj(builder.finish());
     }
 
+    /// Construct a [Center] widget, centering the only child added by `fill`
+    fn center(&mut self, fill: impl FnOnce(&mut Center<'a, D, C>)) {
+        let mut builder = Center::new();
+        fill(&mut builder);
+        self.add_widget_obj(builder.finish());
+    }
+
+    /// Construct a [Stack] widget: the children added by `fill` share the same rect, the last one
+    /// is drawn on top
+    fn overlay(&mut self, fill: impl FnOnce(&mut Stack<'a, D, C>)) {
+        let mut builder = Stack::new();
+        fill(&mut builder);
+        self.add_widget_obj(builder.finish());
+    }
+
     /// Shorthand construct for [LinearLayout] widget. Creates a linear layout with in vertical direction
     fn vertical_linear_layout(
         &mut self,
@@ -412,7 +774,7 @@ where
     ) {
         let mut builder = LinearLayoutBuilder {
             direction: LayoutDirection::Vertical,
-            children: Vec::new(),
+            children: Children::new(),
             ..Default::default()
         }
         .alignment(alignment);
@@ -428,7 +790,7 @@ where
     ) {
         let mut builder = LinearLayoutBuilder {
             direction: LayoutDirection::Horizontal,
-            children: Vec::new(),
+            children: Children::new(),
             ..Default::default()
         }
         .alignment(alignment);
@@ -436,6 +798,21 @@ where
         self.add_widget_obj(builder.finish());
     }
 
+    /// Builds a vertical [LinearLayout] whose subtree draws with the theme edited by `apply`,
+    /// e.g. `|theme| theme.label_color = Rgb888::RED` makes every label inside red
+    fn with_style_override(
+        &mut self,
+        apply: impl Fn(&mut Theme<C>) + 'a,
+        fill: impl FnOnce(&mut LinearLayoutBuilder<'a, D, C>),
+    ) {
+        let mut builder = LinearLayoutBuilder::default();
+        fill(&mut builder);
+        self.add_widget_obj(WidgetObject::new(Box::new(ThemeScope::new(
+            builder.finish(),
+            Box::new(apply),
+        ))));
+    }
+
     /// Shorthand construct for [GridLayout] widget.
     fn grid_layout(
         &mut self,
@@ -444,10 +821,11 @@ where
         fill: impl FnOnce(&mut GridLayoutBuilder<'a, D, C>),
     ) {
         let mut builder = GridLayoutBuilder {
-            children: Vec::new(),
+            children: Children::new(),
             col_fracs: colums,
             gap: 0,
             row_fracs: rows,
+            capacity: None,
         };
         fill(&mut builder);
         self.add_widget_obj(builder.finish());
@@ -459,6 +837,21 @@ where
         self.add_widget(plot);
     }
 
+    /// Shorthand for the [Histogram] widget
+    fn histogram<V: Into<Vec<u32>>>(&mut self, bins: V) {
+        self.add_widget(Histogram::new(bins.into()));
+    }
+
+    /// Shorthand for the [RingProgress] widget, `value` in range 0.0-1.0
+    fn ring_progress(&mut self, value: f32) {
+        self.add_widget(RingProgress::new(value));
+    }
+
+    /// Shorthand for the [Stepper] widget, `current` is a zero-based step index
+    fn stepper(&mut self, steps: usize, current: usize) {
+        self.add_widget(Stepper::new(steps, current));
+    }
+
     fn filler(&mut self, fill: FillStrategy) {
         self.add_widget(Filler::new(fill));
     }
@@ -468,6 +861,14 @@ where
         self.add_widget(Primitive::new(primitive));
     }
 
+    /// Any embedded-graphics drawable built for the computed widget rect
+    fn sized_primitive<P: Drawable<Color = C> + 'a>(
+        &mut self,
+        builder: impl FnMut(Rectangle) -> P + 'a,
+    ) {
+        self.add_widget(SizedPrimitive::new(builder));
+    }
+
     /// Shorthand for the [Slider] widget
     fn slider(&mut self, value: f32, callback: impl FnMut(f32) + 'a) {
         self.add_widget(Slider::new(value, Box::new(callback)));
@@ -475,3 +876,55 @@ where
 
     fn finish(self) -> WidgetObject<'a, D, C>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{mock_context, DrawFlag};
+
+    #[test]
+    fn events_map_to_modifiers() {
+        let cases = [
+            (Event::Idle, Modifier::None),
+            (Event::Focus, Modifier::Focus),
+            (Event::Active(None), Modifier::Active),
+            (Event::Active(Some(Point::new(1, 2))), Modifier::Active),
+            (Event::Drag(Point::zero()), Modifier::Drag),
+        ];
+
+        for (event, modifier) in cases {
+            let event_args = WidgetEvent {
+                event: &event,
+                ..Default::default()
+            };
+            assert_eq!(event_args.get_modifier(), modifier);
+        }
+    }
+
+    #[test]
+    fn nesting_beyond_max_depth_is_truncated() {
+        use embedded_graphics::pixelcolor::Rgb888;
+        use linear_layout::LinearLayoutBuilder;
+
+        let drawn = core::cell::Cell::new(false);
+        let nested = || {
+            let mut ui = WidgetObject::new(Box::new(DrawFlag(&drawn)));
+            for _ in 0..32 {
+                let mut layout = LinearLayoutBuilder::default();
+                layout.add_widget_obj(ui);
+                ui = layout.finish();
+            }
+            ui
+        };
+
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.update(nested());
+        assert!(drawn.get());
+
+        drawn.set(false);
+        ctx.set_max_depth(Some(8));
+        ctx.update(nested());
+        assert!(!drawn.get());
+        assert_eq!(ctx.depth, 0);
+    }
+}
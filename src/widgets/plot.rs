@@ -1,17 +1,64 @@
 use crate::{EventResult, UiContext};
 
-use super::{Widget, WidgetEvent};
-use alloc::vec::Vec;
+use super::{Widget, WidgetEvent, WidgetObject};
+use alloc::{boxed::Box, format, string::String, vec::Vec};
 use embedded_graphics::{
+    mono_font::{iso_8859_16::FONT_4X6, MonoTextStyle},
     prelude::*,
     primitives::{Line, Polyline, PrimitiveStyle, Rectangle},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
 };
 
+/// Colors of the [Plot] elements
+#[derive(Clone, Copy)]
+pub struct PlotStyle<C: PixelColor> {
+    pub background_color: C,
+    pub grid_color: C,
+    pub axis_color: C,
+    pub curve_color: C,
+}
+
+impl<C: PixelColor> PlotStyle<C> {
+    pub fn new(background_color: C, grid_color: C, axis_color: C, curve_color: C) -> Self {
+        Self {
+            background_color,
+            grid_color,
+            axis_color,
+            curve_color,
+        }
+    }
+}
+
+/// Where the [Plot] axes cross
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum PlotOrigin {
+    /// Center of the plot
+    #[default]
+    Center,
+    /// Bottom left corner, usually with [Plot::y_up]
+    BottomLeft,
+    /// Offset from the top left corner of the plot
+    Custom(Point),
+}
+
 /// Simple plotter X/Y widget
+#[derive(Clone)]
 pub struct Plot {
+    /// Data points. Streaming plots keep their samples in a ring, read them in order with
+    /// [Plot::samples]
     pub points: Vec<Point>,
     pub y_scale: f32,
     pub offset: Point,
+    labels: bool,
+    decimate: bool,
+    /// Samples kept by [Plot::push], `None` for plots with the points set directly
+    capacity: Option<usize>,
+    /// Index of the oldest sample in a full streaming plot
+    head: usize,
+    origin: PlotOrigin,
+    y_up: bool,
+    /// Crosshair x position, in pixels from the left edge of the plot
+    cursor: Option<i32>,
 }
 
 impl Plot {
@@ -20,42 +67,293 @@ impl Plot {
             points: Vec::new(),
             offset,
             y_scale: y_scale.clamp(0.1, f32::MAX),
+            labels: false,
+            decimate: false,
+            capacity: None,
+            head: 0,
+            origin: PlotOrigin::Center,
+            y_up: false,
+            cursor: None,
         }
     }
 
-    fn scale_graph(&mut self, rect: Rectangle) -> (Size, Size) {
-        let (min_x, max_x, min_y, max_y) =
-            self.points
-                .iter()
-                .fold((0, 0, 0, 0), |(min_x, max_x, min_y, max_y), &point| {
-                    (
-                        min_x.min(point.x),
-                        max_x.max(point.x),
-                        min_y.min(point.y),
-                        max_y.max(point.y),
-                    )
-                });
+    /// Shows a crosshair on the data point nearest to `x` (pixels from the left edge of the
+    /// plot), with its value in an overlay label (see [UiContext::push_passive_overlay])
+    pub fn cursor(mut self, x: Option<i32>) -> Self {
+        self.cursor = x;
+        self
+    }
 
-        let scale_x = (rect.size.width as f32) / (max_x - min_x) as f32;
-        let scale_y = (rect.size.height as f32) / (max_y - min_y) as f32 * self.y_scale;
+    /// Returns the index of the scaled point horizontally nearest to the pixel column `x`
+    fn nearest_point(scaled: &[Point], x: i32) -> Option<usize> {
+        scaled
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, point)| point.x.abs_diff(x))
+            .map(|(index, _)| index)
+    }
 
-        for point in &mut self.points {
-            let scaled_x = ((point.x - min_x) as f32 * scale_x) as i32 + rect.top_left.x;
-            let scaled_y = ((point.y - min_y) as f32 * scale_y) as i32 + rect.top_left.y;
-            point.x = scaled_x;
-            point.y = scaled_y;
+    /// Sets where the axes cross. The data is placed relative to it, the value 0 on the axes
+    pub fn origin(mut self, origin: PlotOrigin) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Draws increasing y values upwards, like scientific plots. By default they go down like
+    /// screen coordinates
+    pub fn y_up(mut self, y_up: bool) -> Self {
+        self.y_up = y_up;
+        self
+    }
+
+    /// Returns the point where the axes cross, the data value 0 is drawn there
+    fn origin_point(&self, rect: Rectangle) -> Point {
+        match self.origin {
+            PlotOrigin::Center => rect.center(),
+            PlotOrigin::BottomLeft => Point::new(
+                rect.top_left.x,
+                rect.bottom_right().unwrap_or(rect.top_left).y,
+            ),
+            PlotOrigin::Custom(offset) => rect.top_left + offset,
         }
+    }
 
-        (
-            Size::new(min_x as u32, max_y as u32),
-            Size::new(max_x as u32, max_y as u32),
+    /// Streaming plot (like an oscilloscope) showing the last `capacity` samples added with
+    /// [Plot::push]. Keep it outside of the UI tree and add a clone to the UI every frame
+    pub fn streaming(capacity: usize, y_scale: f32, offset: Point) -> Self {
+        Plot {
+            capacity: Some(capacity.max(1)),
+            points: Vec::with_capacity(capacity.max(1)),
+            ..Self::new(y_scale, offset)
+        }
+    }
+
+    /// Adds a sample at the right of a streaming plot (see [Plot::streaming]). Once the plot is
+    /// full the sample replaces the oldest one and the rest scroll left. Without a capacity the
+    /// sample is appended to the points
+    pub fn push(&mut self, value: i32) {
+        match self.capacity {
+            Some(capacity) if self.points.len() >= capacity => {
+                let head = self.head % self.points.len();
+                self.points[head] = Point::new(head as i32, value);
+                self.head = (head + 1) % self.points.len();
+            }
+            _ => self
+                .points
+                .push(Point::new(self.points.len() as i32, value)),
+        }
+    }
+
+    /// Returns the data points in order. Samples of a streaming plot come oldest first with x
+    /// counting from 0
+    pub fn samples(&self) -> impl Iterator<Item = Point> + '_ {
+        let head = self.head.min(self.points.len());
+        let streaming = self.capacity.is_some();
+        self.points[head..]
+            .iter()
+            .chain(&self.points[..head])
+            .enumerate()
+            .map(move |(x, &point)| {
+                if streaming {
+                    Point::new(x as i32, point.y)
+                } else {
+                    point
+                }
+            })
+    }
+
+    /// Draws the data range values along the axes
+    pub fn show_labels(mut self, show: bool) -> Self {
+        self.labels = show;
+        self
+    }
+
+    /// Reduces the data to the lowest and the highest point of every pixel column before
+    /// drawing (min/max decimation). Large series draw much faster and keep their envelope
+    pub fn decimate(mut self, decimate: bool) -> Self {
+        self.decimate = decimate;
+        self
+    }
+
+    /// Returns the lowest and the highest of the scaled `points` in every pixel column, in data
+    /// order. Points right of `max_x` fall into the last column
+    fn decimate_columns(points: &[Point], max_x: i32) -> Vec<Point> {
+        let mut decimated = Vec::new();
+        // extreme points of the current column, in data order
+        let mut column: Option<(Point, Point)> = None;
+
+        for point in points {
+            let point = Point::new(point.x.min(max_x), point.y);
+            column = match column {
+                Some((first, second)) if first.x == point.x => {
+                    let (low, high) = if first.y <= second.y {
+                        (first, second)
+                    } else {
+                        (second, first)
+                    };
+
+                    if point.y < low.y {
+                        Some((high, point))
+                    } else if point.y > high.y {
+                        Some((low, point))
+                    } else {
+                        Some((first, second))
+                    }
+                }
+                previous => {
+                    if let Some((first, second)) = previous {
+                        decimated.push(first);
+                        if second != first {
+                            decimated.push(second);
+                        }
+                    }
+                    Some((point, point))
+                }
+            };
+        }
+
+        if let Some((first, second)) = column {
+            decimated.push(first);
+            if second != first {
+                decimated.push(second);
+            }
+        }
+
+        decimated
+    }
+
+    /// Returns `(min_x, max_x, min_y, max_y)` of the data
+    fn data_range(&self) -> (i32, i32, i32, i32) {
+        let mut samples = self.samples();
+        let first = samples.next().unwrap_or_default();
+        samples.fold(
+            (first.x, first.x, first.y, first.y),
+            |(min_x, max_x, min_y, max_y), point| {
+                (
+                    min_x.min(point.x),
+                    max_x.max(point.x),
+                    min_y.min(point.y),
+                    max_y.max(point.y),
+                )
+            },
+        )
+    }
+
+    /// Returns the pixels per data unit along x and y, the largest scale keeping the data on
+    /// both sides of the `origin` inside `rect`
+    fn scale(&self, rect: Rectangle, origin: Point) -> (f32, f32) {
+        let (min_x, max_x, min_y, max_y) = self.data_range();
+        let bottom_right = rect.bottom_right().unwrap_or(rect.top_left);
+        // `None` without data on that side of the origin
+        let fit = |pixels: i32, extent: i32| {
+            (extent != 0).then(|| pixels.max(0) as f32 / extent.unsigned_abs() as f32)
+        };
+        let fit_both =
+            |a: Option<f32>, b: Option<f32>| a.into_iter().chain(b).reduce(f32::min).unwrap_or(1.0);
+
+        let scale_x = fit_both(
+            fit(bottom_right.x - origin.x, max_x.max(0)),
+            fit(origin.x - rect.top_left.x, min_x.min(0)),
+        );
+        // values drawn above and below the horizontal axis
+        let (up, down) = if self.y_up {
+            (max_y.max(0), min_y.min(0))
+        } else {
+            (min_y.min(0), max_y.max(0))
+        };
+        let scale_y = fit_both(
+            fit(origin.y - rect.top_left.y, up),
+            fit(bottom_right.y - origin.y, down),
+        );
+
+        (scale_x, scale_y * self.y_scale)
+    }
+
+    /// Returns the pixel of the data `point`, relative to the `origin`
+    fn to_pixel(&self, point: Point, origin: Point, (scale_x, scale_y): (f32, f32)) -> Point {
+        let y = (point.y as f32 * scale_y) as i32;
+        Point::new(
+            origin.x + (point.x as f32 * scale_x) as i32,
+            if self.y_up {
+                origin.y - y
+            } else {
+                origin.y + y
+            },
         )
     }
+
+    /// Returns the points scaled to `rect`, the data itself is kept for the next frame
+    fn scale_graph(&self, rect: Rectangle) -> Vec<Point> {
+        let origin = self.origin_point(rect);
+        let scale = self.scale(rect, origin);
+
+        self.samples()
+            .map(|point| self.to_pixel(point, origin, scale))
+            .collect()
+    }
+
+    /// Draws the data range at the axes: X range along the horizontal axis, Y range along the
+    /// vertical one, each value at the position where the curve reaches it
+    fn draw_labels<D: DrawTarget<Color = C>, C: PixelColor>(
+        &self,
+        target: &mut D,
+        rect: Rectangle,
+        range: (i32, i32, i32, i32),
+        color: C,
+    ) {
+        let (min_x, max_x, min_y, max_y) = range;
+        let text_style = MonoTextStyle::new(&FONT_4X6, color);
+        let origin = self.origin_point(rect);
+        let scale = self.scale(rect, origin);
+        let left = self.to_pixel(Point::new(min_x, 0), origin, scale).x;
+        let right = self.to_pixel(Point::new(max_x, 0), origin, scale).x;
+        let min_y_row = self.to_pixel(Point::new(0, min_y), origin, scale).y;
+        let max_y_row = self.to_pixel(Point::new(0, max_y), origin, scale).y;
+        let ((top, top_row), (bottom, bottom_row)) = if self.y_up {
+            ((max_y, max_y_row), (min_y, min_y_row))
+        } else {
+            ((min_y, min_y_row), (max_y, max_y_row))
+        };
+
+        let labels = [
+            (min_x, Point::new(left, origin.y + 2), Alignment::Left, Baseline::Top),
+            (max_x, Point::new(right, origin.y + 2), Alignment::Right, Baseline::Top),
+            (top, Point::new(origin.x + 2, top_row), Alignment::Left, Baseline::Top),
+            (bottom, Point::new(origin.x + 2, bottom_row), Alignment::Left, Baseline::Bottom),
+        ];
+
+        for (value, position, alignment, baseline) in labels {
+            let style = TextStyleBuilder::new()
+                .alignment(alignment)
+                .baseline(baseline)
+                .build();
+            let _ = Text::with_text_style(&format!("{}", value), position, text_style, style)
+                .draw(target);
+        }
+    }
+}
+
+/// Returns the pixels from `start` to `end` of the grid lines `step` pixels apart on both sides
+/// of the axis at `origin`. A grid denser than every other pixel is not drawn
+fn grid_lines(origin: i32, step: f32, start: i32, end: i32) -> impl Iterator<Item = i32> {
+    let (first, last) = if step >= 2.0 {
+        (
+            -(((origin - start) as f32 / step) as i32),
+            ((end - origin) as f32 / step) as i32,
+        )
+    } else {
+        (1, 0)
+    };
+
+    (first..=last)
+        .filter(|&line| line != 0)
+        .map(move |line| origin + (line as f32 * step) as i32)
+        .filter(move |pixel| (start..=end).contains(pixel))
 }
 
 impl<'a, D, C> Widget<'a, D, C> for Plot
 where
-    D: DrawTarget<Color = C>,
+    D: DrawTarget<Color = C> + 'a,
     C: PixelColor + 'a,
 {
     fn size(&mut self, _context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
@@ -72,83 +370,337 @@ where
             return EventResult::Pass
         }
         let style = context.theme.plot_style;
-        let grid_style = PrimitiveStyle::with_stroke(
-            style
-                .background_color
-                .expect("Plot widghet must have a background color for a drawing"),
-            1,
-        );
-        let axis_style = PrimitiveStyle::with_stroke(
-            style
-                .foreground_color
-                .expect("Plot widghet must have a foreground color for a drawing"),
-            2,
-        );
+        let grid_style = PrimitiveStyle::with_stroke(style.grid_color, 1);
+        let axis_style = PrimitiveStyle::with_stroke(style.axis_color, 2);
 
         // draw lines
         let bottom_right = rect.bottom_right().unwrap_or_default();
+        let origin = self.origin_point(rect);
 
         let _ = Line::new(
-            Point::new(rect.top_left.x, rect.center().y),
-            Point::new(bottom_right.x, rect.center().y),
+            Point::new(rect.top_left.x, origin.y),
+            Point::new(bottom_right.x, origin.y),
         )
         .into_styled(axis_style)
         .draw(&mut context.draw_target);
 
         let _ = Line::new(
-            Point::new(rect.center().x, rect.top_left.y),
-            Point::new(rect.center().x, bottom_right.y),
+            Point::new(origin.x, rect.top_left.y),
+            Point::new(origin.x, bottom_right.y),
         )
         .into_styled(axis_style)
         .draw(&mut context.draw_target);
 
-        let (min_size, max_size) = self.scale_graph(rect);
-
-        let start_x = (min_size.width / 10) * 10;
-        let scale_x = (rect.size.width as f32) / (max_size.width - min_size.width) as f32;
+        let range = self.data_range();
+        let mut scaled = self.scale_graph(rect);
+        // the decimation drops points, take the crosshair position first
+        let cursor = self.cursor.and_then(|x| {
+            let index = Self::nearest_point(&scaled, rect.top_left.x + x)?;
+            Some((scaled[index] + self.offset, self.samples().nth(index)?))
+        });
+        if self.decimate {
+            scaled = Self::decimate_columns(&scaled, bottom_right.x);
+        }
 
-        // draw grid
+        // draw grid, every 10 data units from the origin
+        let (scale_x, scale_y) = self.scale(rect, origin);
         if self.y_scale > 0.2 {
-            for x in (start_x..)
-                .step_by(10_usize)
-                .take_while(|&x| x <= min_size.width + rect.size.width)
-            {
-                let scaled_x = ((x - min_size.width) as f32 * scale_x) as i32 + rect.top_left.x;
-                if scaled_x > bottom_right.x {
-                    break;
-                }
+            for x in grid_lines(origin.x, 10.0 * scale_x, rect.top_left.x, bottom_right.x) {
                 let _ = Line::new(
-                    Point::new(scaled_x, rect.top_left.y),
-                    Point::new(scaled_x, bottom_right.y),
+                    Point::new(x, rect.top_left.y),
+                    Point::new(x, bottom_right.y),
                 )
                 .into_styled(grid_style)
                 .draw(&mut context.draw_target);
             }
 
-            let step_y = (10.0 * self.y_scale) as i32;
-            for y in (rect.top_left.y..rect.bottom_right().unwrap_or_default().y)
-                .step_by(step_y as usize)
-                .take_while(|&y| y <= min_size.height as i32 + rect.size.height as i32)
-            {
+            for y in grid_lines(origin.y, 10.0 * scale_y, rect.top_left.y, bottom_right.y) {
                 let _ = Line::new(
                     Point::new(rect.top_left.x, y),
-                    Point::new(rect.bottom_right().unwrap_or_default().x, y),
+                    Point::new(bottom_right.x, y),
                 )
                 .into_styled(grid_style)
                 .draw(&mut context.draw_target);
             }
         }
 
-        let _ = Polyline::new(&self.points)
-            .into_styled(PrimitiveStyle::with_stroke(
-                style
-                    .accent_color
-                    .expect("Plot widghet must have a accent color for a drawing"),
-                1,
-            ))
+        let _ = Polyline::new(&scaled)
+            .into_styled(PrimitiveStyle::with_stroke(style.curve_color, 1))
             .translate(self.offset)
             .draw(&mut context.draw_target);
 
+        if self.labels {
+            self.draw_labels(&mut context.draw_target, rect, range, context.theme.label_color);
+        }
+
+        if let Some((position, value)) = cursor {
+            let cursor_style = PrimitiveStyle::with_stroke(context.theme.label_color, 1);
+            let _ = Line::new(
+                Point::new(position.x, rect.top_left.y),
+                Point::new(position.x, bottom_right.y),
+            )
+            .into_styled(cursor_style)
+            .draw(&mut context.draw_target);
+            let _ = Line::new(
+                Point::new(rect.top_left.x, position.y),
+                Point::new(bottom_right.x, position.y),
+            )
+            .into_styled(cursor_style)
+            .draw(&mut context.draw_target);
+
+            let text = format!("{}, {}", value.x, value.y);
+            let size = Size::new(
+                FONT_4X6.character_size.width * text.len() as u32 + 2,
+                FONT_4X6.character_size.height + 2,
+            );
+            // next to the point, kept inside the plot
+            let label_position = Point::new(
+                (position.x + 2).min(bottom_right.x - size.width as i32 + 1),
+                (position.y - size.height as i32 - 1).max(rect.top_left.y),
+            );
+            context.push_passive_overlay(
+                Rectangle::new(label_position, size),
+                WidgetObject::new(Box::new(CursorLabel { text })),
+            );
+        }
+
         EventResult::Pass
     }
 }
+
+/// Value of the data point under the [Plot::cursor]
+struct CursorLabel {
+    text: String,
+}
+
+impl<'a, D, C> Widget<'a, D, C> for CursorLabel
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        _event_args: WidgetEvent,
+    ) -> EventResult {
+        let _ = context
+            .draw_target
+            .fill_solid(&rect, context.theme.plot_style.background_color);
+        let text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Middle)
+            .build();
+        let _ = Text::with_text_style(
+            &self.text,
+            rect.center(),
+            MonoTextStyle::new(&FONT_4X6, context.theme.label_color),
+            text_style,
+        )
+        .draw(&mut context.draw_target);
+
+        EventResult::Pass
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mock_context;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb888};
+
+    fn render(grid_color: Rgb888) -> MockDisplay<Rgb888> {
+        render_plot(Plot::new(1.0, Point::zero()), grid_color)
+    }
+
+    fn render_plot(mut plot: Plot, grid_color: Rgb888) -> MockDisplay<Rgb888> {
+        let mut ctx = mock_context();
+        ctx.theme.plot_style =
+            PlotStyle::new(Rgb888::BLACK, grid_color, Rgb888::BLUE, Rgb888::GREEN);
+        ctx.theme.label_color = Rgb888::WHITE;
+
+        plot.points = [(0, 0), (10, 30), (20, 10), (30, 40)]
+            .into_iter()
+            .map(|(x, y)| Point::new(x, y))
+            .collect();
+        plot.draw(
+            &mut ctx,
+            Rectangle::new(Point::zero(), Size::new(60, 60)),
+            WidgetEvent::default(),
+        );
+        ctx.draw_target
+    }
+
+    fn count(display: &MockDisplay<Rgb888>, color: Rgb888) -> usize {
+        count_in(display, display.bounding_box(), color)
+    }
+
+    fn count_in(display: &MockDisplay<Rgb888>, area: Rectangle, color: Rgb888) -> usize {
+        area.points()
+            .filter(|&point| display.get_pixel(point) == Some(color))
+            .count()
+    }
+
+    #[test]
+    fn labels_drawn_at_range_ticks() {
+        let plain = render_plot(Plot::new(1.0, Point::zero()), Rgb888::RED);
+        assert_eq!(count(&plain, Rgb888::WHITE), 0);
+
+        let labeled = render_plot(
+            Plot::new(1.0, Point::zero()).show_labels(true),
+            Rgb888::RED,
+        );
+        // the data starts at the origin in the center
+        let corners = [
+            Rectangle::new(Point::new(30, 32), Size::new(8, 6)),
+            Rectangle::new(Point::new(52, 32), Size::new(8, 6)),
+            Rectangle::new(Point::new(32, 30), Size::new(8, 6)),
+            Rectangle::new(Point::new(32, 54), Size::new(8, 6)),
+        ];
+
+        for corner in corners {
+            assert!(count_in(&labeled, corner, Rgb888::WHITE) > 0);
+        }
+    }
+
+    #[test]
+    fn decimation_keeps_two_points_per_column() {
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.draw_target.set_allow_overdraw(true);
+        let rect = Rectangle::new(Point::zero(), Size::new(60, 60));
+        let mut plot = Plot::new(1.0, Point::zero()).decimate(true);
+        plot.points = (0..10000).map(|x| Point::new(x, (x * 37) % 100)).collect();
+
+        let decimated = Plot::decimate_columns(&plot.scale_graph(rect), 59);
+        assert!(decimated.len() <= 2 * rect.size.width as usize);
+        // the envelope is kept
+        assert_eq!(decimated.iter().map(|point| point.y).min(), Some(29));
+        assert_eq!(decimated.iter().map(|point| point.y).max(), Some(59));
+
+        // drawing decimates a scratch copy, the data is kept for the next frame
+        plot.draw(&mut ctx, rect, WidgetEvent::default());
+        assert_eq!(plot.points.len(), 10000);
+        assert_eq!(plot.points[1], Point::new(1, 37));
+    }
+
+    #[test]
+    fn oldest_sample_scrolls_off() {
+        let mut plot = Plot::streaming(3, 1.0, Point::zero());
+        for value in [10, 20, 30, 40] {
+            plot.push(value);
+        }
+
+        assert_eq!(
+            plot.samples().collect::<Vec<_>>(),
+            [Point::new(0, 20), Point::new(1, 30), Point::new(2, 40)]
+        );
+
+        // the ring wraps around more than once
+        for value in [50, 60, 70] {
+            plot.push(value);
+        }
+        assert_eq!(
+            plot.samples().collect::<Vec<_>>(),
+            [Point::new(0, 50), Point::new(1, 60), Point::new(2, 70)]
+        );
+    }
+
+    #[test]
+    fn bottom_left_origin_draws_positive_values_above_axis() {
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.draw_target.set_allow_overdraw(true);
+        ctx.theme.plot_style =
+            PlotStyle::new(Rgb888::BLACK, Rgb888::RED, Rgb888::BLUE, Rgb888::GREEN);
+        let mut plot = Plot::new(1.0, Point::zero())
+            .origin(PlotOrigin::BottomLeft)
+            .y_up(true);
+        plot.points = [(0, 0), (10, 30), (20, 40)]
+            .into_iter()
+            .map(|(x, y)| Point::new(x, y))
+            .collect();
+
+        plot.draw(
+            &mut ctx,
+            Rectangle::new(Point::zero(), Size::new(60, 60)),
+            WidgetEvent::default(),
+        );
+
+        // the horizontal axis is the bottom row, (10, 30) is 44 rows above it
+        assert_eq!(
+            ctx.draw_target.get_pixel(Point::new(45, 59)),
+            Some(Rgb888::BLUE)
+        );
+        assert_eq!(
+            ctx.draw_target.get_pixel(Point::new(29, 15)),
+            Some(Rgb888::GREEN)
+        );
+    }
+
+    #[test]
+    fn points_placed_relative_to_origin() {
+        let rect = Rectangle::new(Point::zero(), Size::new(61, 61));
+        let mut plot = Plot::new(1.0, Point::zero())
+            .origin(PlotOrigin::Custom(Point::new(20, 40)))
+            .y_up(true);
+        plot.points = [(-10, -10), (0, 0), (20, 20)]
+            .into_iter()
+            .map(|(x, y)| Point::new(x, y))
+            .collect();
+
+        // 2px per unit fits both sides, the value 0 sits on the origin
+        let origin = plot.origin_point(rect);
+        assert_eq!(
+            plot.scale_graph(rect),
+            [
+                origin + Point::new(-20, 20),
+                origin,
+                origin + Point::new(40, -40)
+            ]
+        );
+
+        // the range is the data, not widened to 0
+        plot.points = [Point::new(5, 7), Point::new(9, 12)].into();
+        assert_eq!(plot.data_range(), (5, 9, 7, 12));
+    }
+
+    #[test]
+    fn cursor_snaps_to_nearest_point() {
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.draw_target.set_allow_overdraw(true);
+        ctx.theme.plot_style =
+            PlotStyle::new(Rgb888::BLACK, Rgb888::RED, Rgb888::BLUE, Rgb888::GREEN);
+        ctx.theme.label_color = Rgb888::WHITE;
+        let mut plot = Plot::new(1.0, Point::zero()).cursor(Some(38));
+        plot.points = [(0, 0), (10, 30), (20, 10), (30, 40)]
+            .into_iter()
+            .map(|(x, y)| Point::new(x, y))
+            .collect();
+
+        plot.draw(
+            &mut ctx,
+            Rectangle::new(Point::zero(), Size::new(60, 60)),
+            WidgetEvent::default(),
+        );
+
+        // 38px is closest to x = 10, scaled to the column 39 right of the centered origin
+        let column = Rectangle::new(Point::new(39, 0), Size::new(1, 60));
+        let wrong_column = Rectangle::new(Point::new(38, 0), Size::new(1, 60));
+        assert!(count_in(&ctx.draw_target, column, Rgb888::WHITE) > 50);
+        assert_eq!(count_in(&ctx.draw_target, wrong_column, Rgb888::WHITE), 1);
+        assert_eq!(ctx.overlays.len(), 1);
+    }
+
+    #[test]
+    fn grid_color_does_not_affect_curve() {
+        let red_grid = render(Rgb888::RED);
+        let yellow_grid = render(Rgb888::YELLOW);
+
+        assert!(count(&red_grid, Rgb888::RED) > 0);
+        assert_eq!(count(&yellow_grid, Rgb888::RED), 0);
+        assert!(count(&red_grid, Rgb888::GREEN) > 0);
+        assert_eq!(
+            count(&red_grid, Rgb888::GREEN),
+            count(&yellow_grid, Rgb888::GREEN)
+        );
+    }
+}
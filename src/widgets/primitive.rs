@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 use crate::{EventResult, UiContext};
 
 use super::{Widget, WidgetEvent};
@@ -38,3 +40,64 @@ where
         EventResult::Pass
     }
 }
+
+/// Widget which builds a [Drawable] for the computed rect every frame, e.g. a styled rectangle
+/// filling the layout cell. Occupies all available space
+pub struct SizedPrimitive<'a, P> {
+    builder: Box<dyn FnMut(Rectangle) -> P + 'a>,
+}
+
+impl<'a, P> SizedPrimitive<'a, P> {
+    pub fn new(builder: impl FnMut(Rectangle) -> P + 'a) -> Self {
+        Self {
+            builder: Box::new(builder),
+        }
+    }
+}
+
+impl<'a, D, C, P> Widget<'a, D, C> for SizedPrimitive<'a, P>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor + 'a,
+    P: Drawable<Color = C> + 'a,
+{
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        _event_args: WidgetEvent,
+    ) -> EventResult {
+        let _ = (self.builder)(rect).draw(&mut context.draw_target);
+        EventResult::Pass
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testing::mock_context, widgets::WidgetObject, SystemEvent};
+    use core::cell::Cell;
+    use embedded_graphics::{
+        pixelcolor::Rgb888,
+        primitives::{Primitive as _, PrimitiveStyle},
+    };
+
+    #[test]
+    fn sized_primitive_fills_computed_rect() {
+        let produced = Cell::new(Rectangle::zero());
+        let mut ctx = mock_context::<Rgb888>();
+
+        let mut widget = WidgetObject::new(Box::new(SizedPrimitive::new(|rect: Rectangle| {
+            produced.set(rect);
+            rect.into_styled(PrimitiveStyle::with_fill(Rgb888::RED))
+        })));
+
+        let rect = Rectangle::new(Point::new(4, 6), Size::new(20, 10));
+        widget.size(&mut ctx, rect.size);
+        widget.layout(&mut ctx, rect);
+        widget.draw(&mut ctx, &SystemEvent::Idle);
+
+        assert_eq!(produced.get(), rect);
+        assert_eq!(ctx.draw_target.affected_area(), rect);
+    }
+}
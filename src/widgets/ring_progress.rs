@@ -0,0 +1,127 @@
+use alloc::format;
+use embedded_graphics::{
+    mono_font::{iso_8859_16::FONT_4X6, MonoTextStyle},
+    prelude::*,
+    primitives::{Arc, Circle, PrimitiveStyle, Rectangle},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
+};
+
+use super::{gauge::GaugeStyle, Widget, WidgetEvent};
+use crate::{EventResult, UiContext};
+
+/// Ring (donut) progress indicator. The arc starts at the top and fills clockwise, the track
+/// uses the progress style background color and the arc uses the accent color
+pub struct RingProgress {
+    pub value: f32,
+    /// Draws the value in percents in the center of the ring
+    pub show_percentage: bool,
+}
+
+impl RingProgress {
+    pub fn new(value: f32) -> Self {
+        Self {
+            value: value.clamp(0.0, 1.0),
+            show_percentage: false,
+        }
+    }
+
+    /// Returns the arc start and sweep for the current value
+    fn arc_angles(&self) -> (Angle, Angle) {
+        let dial = GaugeStyle::full_circle();
+        let start = dial.value_angle(0.0);
+        (
+            Angle::from_degrees(start + 90.0),
+            Angle::from_degrees(dial.value_angle(self.value) - start),
+        )
+    }
+}
+
+impl<'a, D, C> Widget<'a, D, C> for RingProgress
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor + 'a,
+{
+    fn size(&mut self, _context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
+        let side = hint.width.min(hint.height);
+        Size::new(side, side)
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        _event_args: WidgetEvent,
+    ) -> EventResult {
+        let style = context.theme.progress_style;
+        let fill_color = style
+            .accent_color
+            .expect("RingProgress must have a accent color for a drawing");
+        let ring_width = style.stroke_width.clamp(2, u32::MAX);
+
+        let diameter = rect.size.width.min(rect.size.height);
+        if diameter <= ring_width {
+            return EventResult::Pass;
+        }
+
+        let circle = Circle::with_center(rect.center(), diameter - ring_width);
+
+        if let Some(track_color) = style.background_color {
+            let _ = circle
+                .into_styled(PrimitiveStyle::with_stroke(track_color, ring_width))
+                .draw(&mut context.draw_target);
+        }
+
+        let (start, sweep) = self.arc_angles();
+        if sweep.to_degrees() > 0.0 {
+            let _ = Arc::from_circle(circle, start, sweep)
+                .into_styled(PrimitiveStyle::with_stroke(fill_color, ring_width))
+                .draw(&mut context.draw_target);
+        }
+
+        if self.show_percentage {
+            let text_style = TextStyleBuilder::new()
+                .alignment(Alignment::Center)
+                .baseline(Baseline::Middle)
+                .build();
+
+            let _ = Text::with_text_style(
+                &format!("{}%", (self.value * 100.0 + 0.5) as u32),
+                rect.center(),
+                MonoTextStyle::new(&FONT_4X6, style.foreground_color.unwrap_or(fill_color)),
+                text_style,
+            )
+            .draw(&mut context.draw_target);
+        }
+
+        EventResult::Pass
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mock_context;
+    use embedded_graphics::pixelcolor::Rgb888;
+
+    #[test]
+    fn quarter_value_sweeps_quarter_arc() {
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.theme.progress_style.accent_color = Some(Rgb888::RED);
+
+        let rect = Rectangle::new(Point::zero(), Size::new(41, 41));
+        RingProgress::new(0.25).draw(&mut ctx, rect, WidgetEvent::default());
+
+        let center = rect.center();
+        let filled: alloc::vec::Vec<Point> = rect
+            .points()
+            .filter(|&point| ctx.draw_target.get_pixel(point) == Some(Rgb888::RED))
+            .collect();
+
+        assert!(!filled.is_empty());
+        assert!(filled
+            .iter()
+            .all(|point| point.x >= center.x && point.y <= center.y));
+        assert!(filled.iter().any(|point| point.x - center.x > 15));
+        assert!(filled.iter().any(|point| center.y - point.y > 15));
+    }
+}
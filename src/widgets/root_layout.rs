@@ -1,7 +1,7 @@
 use alloc::{boxed::Box, vec::Vec};
 use embedded_graphics::{prelude::*, primitives::Rectangle};
 
-use super::{Widget, WidgetEvent, WidgetObject};
+use super::{to_coord, StackedTarget, Widget, WidgetEvent, WidgetObject};
 use crate::{EventResult, SystemEvent, UiContext};
 
 #[derive(Clone, Copy, PartialEq)]
@@ -72,11 +72,7 @@ where
         let mut size = Size::zero();
 
         for child in self.children.iter_mut() {
-            let child_size = child.widget_object.size(context, child.dimensions.size);
-            size += child_size;
-            if child.dimensions.size == Size::zero() {
-                child.dimensions.size = child_size;
-            }
+            size += child.widget_object.size(context, child.dimensions.size);
         }
 
         size
@@ -84,15 +80,26 @@ where
 
     fn layout(&mut self, context: &mut UiContext<'a, D, C>, rect: Rectangle) {
         for child in self.children.iter_mut() {
+            // children without a size take the measured one, measured result is cached
+            let child_size = if child.dimensions.size == Size::zero() {
+                child.widget_object.size(context, child.dimensions.size)
+            } else {
+                child.dimensions.size
+            };
+
             match child.anchor {
                 Anchor::TopLeft => {
-                    child.widget_object.layout(context, child.dimensions);
+                    let child_rect = Rectangle::new(child.dimensions.top_left, child_size);
+                    child.widget_object.layout(context, child_rect);
                 }
                 Anchor::Center => {
-                    let centered_pos = rect.top_left
-                        + (rect.size / 2)
-                        - (child.dimensions.size / 2);
-                    let centered_rect = Rectangle::new(centered_pos, child.dimensions.size);
+                    // a child larger than the parent sticks to the parent top left corner
+                    let free_space = rect.size.saturating_sub(child_size);
+                    let centered_pos = Point::new(
+                        rect.top_left.x.saturating_add(to_coord(free_space.width / 2)),
+                        rect.top_left.y.saturating_add(to_coord(free_space.height / 2)),
+                    );
+                    let centered_rect = Rectangle::new(centered_pos, child_size);
                     child.widget_object.layout(context, centered_rect);
                 }
             }
@@ -106,15 +113,84 @@ where
         event_args: WidgetEvent,
     ) -> EventResult {
         let mut event_result = EventResult::Pass;
+        let target = StackedTarget::new(
+            self.children
+                .iter()
+                .map(|child| child.widget_object.computed_rect),
+            event_args.system_event,
+        );
 
-        for child in self.children.iter_mut() {
-            if event_result == EventResult::Stop || !child.exclusive {
-                event_result = child.widget_object.draw(context, &SystemEvent::Idle);
+        for (index, child) in self.children.iter_mut().enumerate() {
+            let event = if child.exclusive && target.includes(index) {
+                event_args.system_event
             } else {
-                event_result = child.widget_object.draw(context, event_args.system_event);
-            }
+                &SystemEvent::Idle
+            };
+            child
+                .widget_object
+                .draw_sibling(context, event, &mut event_result);
         }
 
         event_result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::mock_context,
+        widgets::{
+            button::Button,
+            filler::{FillStrategy, Filler},
+        },
+    };
+    use core::cell::Cell;
+    use embedded_graphics::{mono_font::ascii::FONT_4X6, pixelcolor::Rgb888};
+
+    #[test]
+    fn only_topmost_child_gets_click() {
+        let pressed = [Cell::new(0), Cell::new(0)];
+        let mut ctx = mock_context::<Rgb888>();
+
+        let mut root = RootLayout::new();
+        for (counter, top_left) in pressed.iter().zip([Point::new(0, 0), Point::new(10, 10)]) {
+            root.add_widget_obj(
+                WidgetObject::new(Box::new(Button::new(
+                    "button".into(),
+                    &FONT_4X6,
+                    Box::new(move || counter.set(counter.get() + 1)),
+                ))),
+                Rectangle::new(top_left, Size::new(30, 20)),
+                true,
+                Anchor::TopLeft,
+            );
+        }
+
+        let mut root = root.finish();
+        let bounds = ctx.draw_target.bounding_box();
+        root.size(&mut ctx, bounds.size);
+        root.layout(&mut ctx, bounds);
+        root.draw(&mut ctx, &SystemEvent::Active(Point::new(15, 15)));
+
+        assert_eq!((pressed[0].get(), pressed[1].get()), (0, 1));
+    }
+
+    #[test]
+    fn centered_child_larger_than_parent_is_clamped() {
+        let mut ctx = mock_context::<Rgb888>();
+        let parent = Rectangle::new(Point::new(10, 10), Size::new(20, 20));
+
+        let mut root = RootLayout::new();
+        root.add_widget_obj(
+            WidgetObject::new(Box::new(Filler::new(FillStrategy::Both))),
+            Rectangle::new(Point::zero(), Size::new(u32::MAX, 10)),
+            true,
+            Anchor::Center,
+        );
+        root.layout(&mut ctx, parent);
+
+        let child = root.children[0].widget_object.computed_rect;
+        assert_eq!(child.top_left, Point::new(10, 15));
+    }
+}
@@ -1,11 +1,14 @@
 
-use alloc::boxed::Box;
+use alloc::{boxed::Box, format, string::String};
+use core::cell::Cell;
 use embedded_graphics::{
+    mono_font::{MonoFont, MonoTextStyle},
     prelude::*,
-    primitives::{PrimitiveStyle, Rectangle},
+    primitives::{PrimitiveStyle, Rectangle, StrokeAlignment},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
 };
 
-use super::{Widget, WidgetEvent};
+use super::{finite_value, to_coord, Widget, WidgetEvent, WidgetObject};
 use crate::{themes::DynamicStyle, Event, EventResult, SystemEvent, UiContext};
 
 #[derive(Clone, Copy, Default)]
@@ -32,11 +35,95 @@ impl<C: PixelColor> SliderStyle<C> {
     }
 }
 
+/// Auto-repeat acceleration for `Increase`/`Decrease` events. Keep it outside of the UI tree,
+/// it counts the frames the same event has been arriving in a row (e.g. while a key is held).
+/// Sliders borrow it shared, so the same state can be passed to the slider rebuilt every frame
+#[derive(Clone, Debug, Default)]
+pub struct HoldAcceleration {
+    /// Frame and direction (`true` for `Increase`) of the previous step
+    last: Cell<Option<(u32, bool)>>,
+    held_frames: Cell<u32>,
+}
+
+impl HoldAcceleration {
+    /// Frames before the step grows by one more multiple of the base step
+    const FRAMES_PER_LEVEL: u32 = 10;
+    const MAX_MULTIPLIER: u32 = 8;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the accelerated `step` for an event received at `frame`. The step grows the
+    /// longer the event repeats on consecutive frames and resets when it stops or changes
+    /// direction
+    pub fn step(&self, frame: u32, step: f32, increase: bool) -> f32 {
+        let held_frames = match self.last.get() {
+            Some((last_frame, direction))
+                if direction == increase && frame.wrapping_sub(last_frame) == 1 =>
+            {
+                self.held_frames.get() + 1
+            }
+            _ => 0,
+        };
+        self.held_frames.set(held_frames);
+        self.last.set(Some((frame, increase)));
+
+        let multiplier = (1 + held_frames / Self::FRAMES_PER_LEVEL).min(Self::MAX_MULTIPLIER);
+        step * multiplier as f32
+    }
+}
+
+/// Length of the tick marks under the track, see [Slider::ticks]
+const TICK_LENGTH: u32 = 3;
+/// Space between the value bubble border and its text, see [Slider::value_bubble]
+const BUBBLE_PADDING: u32 = 2;
+
+/// Value shown above the handle while dragging, drawn in the modal style
+struct ValueBubble<'a> {
+    text: String,
+    font: &'a MonoFont<'a>,
+}
+
+impl<'a, D, C> Widget<'a, D, C> for ValueBubble<'a>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        _event_args: WidgetEvent,
+    ) -> EventResult {
+        let mut style: PrimitiveStyle<C> = context.theme.modal_style.into();
+        style.stroke_alignment = StrokeAlignment::Inside;
+        let _ = rect.into_styled(style).draw(&mut context.draw_target);
+
+        let text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Middle)
+            .build();
+        let _ = Text::with_text_style(
+            &self.text,
+            rect.center(),
+            MonoTextStyle::new(self.font, context.theme.label_color),
+            text_style,
+        )
+        .draw(&mut context.draw_target);
+
+        EventResult::Pass
+    }
+}
+
 /// Slider
 pub struct Slider<'a, C: PixelColor> {
     value: f32,
     callback: Box<dyn FnMut(f32) + 'a>,
     style: Option<SliderStyle<C>>,
+    hold: Option<&'a HoldAcceleration>,
+    ticks: u32,
+    bubble_font: Option<&'a MonoFont<'a>>,
 }
 
 impl<'a, C> Slider<'a, C>
@@ -45,9 +132,12 @@ where
 {
     pub fn new(value: f32, callback: Box<dyn FnMut(f32) + 'a>) -> Self {
         Self {
-            value,
+            value: finite_value(value),
             callback,
             style: None,
+            hold: None,
+            ticks: 0,
+            bubble_font: None,
         }
     }
 
@@ -57,9 +147,85 @@ where
         callback: Box<dyn FnMut(f32) + 'a>,
     ) -> Self {
         Self {
-            value,
+            value: finite_value(value),
             callback,
             style: Some(style),
+            hold: None,
+            ticks: 0,
+            bubble_font: None,
+        }
+    }
+
+    /// Makes held `Increase`/`Decrease` events take increasingly large steps
+    pub fn accelerate(mut self, hold: &'a HoldAcceleration) -> Self {
+        self.hold = Some(hold);
+        self
+    }
+
+    /// Draws `ticks` evenly spaced marks under the track with the track stroke color, the first
+    /// and the last one at the ends of the handle travel. Less than 2 ticks draw none
+    pub fn ticks(mut self, ticks: u32) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Shows the value in percent in a bubble above the handle while it is dragged. The bubble is
+    /// a passive overlay (see [UiContext::push_passive_overlay]), so it is not clipped by the
+    /// layout, but it stays inside of the viewport (see [UiContext::set_viewport])
+    pub fn value_bubble(mut self, font: &'a MonoFont<'a>) -> Self {
+        self.bubble_font = Some(font);
+        self
+    }
+
+    /// Text of the value bubble
+    fn value_text(&self) -> String {
+        format!("{}%", (self.value.clamp(0.0, 1.0) * 100.0 + 0.5) as u32)
+    }
+
+    /// Queues the value bubble centered above the handle, kept within the slider width. At the
+    /// top edge of the viewport the bubble is moved down over the slider
+    fn push_bubble<D: DrawTarget<Color = C>>(
+        &self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        handle_width: u32,
+    ) {
+        let Some(font) = self.bubble_font else {
+            return;
+        };
+
+        let text = self.value_text();
+        let size = Size::new(
+            font.character_size.width * text.len() as u32 + 2 * BUBBLE_PADDING,
+            font.character_size.height + 2 * BUBBLE_PADDING,
+        );
+        let travel = rect.size.width.saturating_sub(handle_width);
+        let handle_center = (handle_width / 2) as i32 + (travel as f32 * self.value) as i32;
+        let x = (handle_center - (size.width / 2) as i32)
+            .min(to_coord(rect.size.width.saturating_sub(size.width)))
+            .max(0);
+        let y = (rect.top_left.y - to_coord(size.height) - 1).max(context.viewport().top_left.y);
+        let bubble_rect = Rectangle::new(Point::new(rect.top_left.x + x, y), size);
+
+        context.push_passive_overlay(
+            bubble_rect,
+            WidgetObject::new(Box::new(ValueBubble { text, font })),
+        );
+    }
+
+    /// Height taken by the tick marks
+    fn ticks_height(&self) -> u32 {
+        if self.ticks >= 2 {
+            TICK_LENGTH
+        } else {
+            0
+        }
+    }
+
+    fn held_step(&self, frame: u32, step: f32, increase: bool) -> f32 {
+        match self.hold {
+            Some(hold) => hold.step(frame, step, increase),
+            None => step,
         }
     }
 
@@ -75,9 +241,13 @@ where
     C: PixelColor + 'a,
 {
     fn size(&mut self, context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
+        let ticks_height = self.ticks_height();
         let style = self.style.get_or_insert(context.theme.slider_style);
 
-        Size::new(hint.width, style.track_height.max(style.handle_size.height) + 2)
+        Size::new(
+            hint.width,
+            style.track_height.max(style.handle_size.height) + 2 + ticks_height,
+        )
     }
 
     fn is_interactive(&mut self) -> bool {
@@ -86,7 +256,7 @@ where
 
     fn max_size(&mut self) -> Size {
         let style = self.style.unwrap();
-        Size::new(u32::MAX, style.handle_size.height + 2)
+        Size::new(u32::MAX, style.handle_size.height + 2 + self.ticks_height())
     }
 
     fn draw(
@@ -95,10 +265,11 @@ where
         rect: Rectangle,
         event_args: WidgetEvent,
     ) -> EventResult {
+        let ticks_height = self.ticks_height();
         let style = self.style.get_or_insert(context.theme.slider_style);
 
-        let handle_style = style.handle_style.style(event_args.event);
-        let track_style = style.track_style.style(event_args.event);
+        let handle_style = context.style(&style.handle_style, event_args.event);
+        let track_style = context.style(&style.track_style, event_args.event);
 
         let track_rect = Rectangle::new(
             Point::new(
@@ -113,6 +284,20 @@ where
             .into_styled::<PrimitiveStyle<C>>(track_style.into())
             .draw(&mut context.draw_target);
 
+        if let Some(color) = track_style.stroke_color.filter(|_| ticks_height > 0) {
+            // ticks are centered on the handle positions
+            let travel = rect.size.width.saturating_sub(style.handle_size.width);
+            let tick_top = rect.top_left.y + (rect.size.height - ticks_height) as i32;
+            for tick in 0..self.ticks {
+                let x = rect.top_left.x
+                    + (style.handle_size.width / 2) as i32
+                    + (travel as u64 * tick as u64 / (self.ticks - 1) as u64) as i32;
+                let _ = Rectangle::new(Point::new(x, tick_top), Size::new(1, ticks_height))
+                    .into_styled(PrimitiveStyle::with_fill(color))
+                    .draw(&mut context.draw_target);
+            }
+        }
+
         let handle_position_x = rect.top_left.x + ((rect.size.width - style.handle_size.width) as f32 * self.value) as i32;
         let _ = Rectangle::new(
             Point::new(
@@ -140,12 +325,12 @@ where
 
             match event_args.system_event {
                 SystemEvent::Increase(step) => {
-                    self.value += step;
+                    self.value += self.held_step(context.frame_counter(), *step, true);
                     (self.callback)(self.value);
                 }
 
                 SystemEvent::Decrease(step) => {
-                    self.value -= step;
+                    self.value -= self.held_step(context.frame_counter(), *step, false);
                     (self.callback)(self.value);
                 }
 
@@ -165,6 +350,8 @@ where
                 context.focused_element = event_args.id;
                 self.pos_to_value(rect, *position);
                 (self.callback)(self.value);
+                let handle_width = self.style.unwrap().handle_size.width;
+                self.push_bubble(context, rect, handle_width);
                 EventResult::Stop
             }
             _ => EventResult::Pass,
@@ -175,8 +362,19 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::themes::hope_diamond::{self};
-    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb565};
+    use crate::{
+        testing::{framebuffer_context, mock_context},
+        themes::{
+            hope_diamond::{self},
+            WidgetStyle,
+        },
+        widgets::{linear_layout::LinearLayoutBuilder, UiBuilder},
+    };
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        mono_font::ascii::FONT_4X6,
+        pixelcolor::{Rgb565, Rgb888},
+    };
 
     #[test]
     fn slider_size() {
@@ -195,4 +393,124 @@ mod tests {
         // because of 2 pixel padding for selection box
         assert_eq!(slider_size.height, 5 + 2);
     }
+
+    #[test]
+    fn non_finite_values_are_sanitized() {
+        let render = |value: f32| {
+            let mut ctx = mock_context::<Rgb565>();
+            ctx.draw_target.set_allow_out_of_bounds_drawing(true);
+            let mut slider = Slider::new(value, Box::new(|_| {}));
+            let rect = Rectangle::new(Point::zero(), Size::new(40, 10));
+            slider.size(&mut ctx, rect.size);
+            slider.draw(&mut ctx, rect, WidgetEvent::default());
+            assert!(slider.value.is_finite());
+            ctx.draw_target
+        };
+
+        assert_eq!(render(f32::NAN), render(0.0));
+        assert_eq!(render(f32::INFINITY), render(1.0));
+    }
+
+    #[test]
+    fn ticks_are_evenly_spaced() {
+        let size = Size::new(41, 10);
+        let mut ctx = framebuffer_context(size);
+        let style = SliderStyle::new(
+            WidgetStyle::new().storke(1, Rgb888::RED).into(),
+            WidgetStyle::new().background_color(Rgb888::GREEN).into(),
+            1,
+            Size::new(1, 5),
+        );
+        let mut slider = Slider::new_with_style(style, 0.5, Box::new(|_| {})).ticks(5);
+        let rect = Rectangle::new(Point::zero(), slider.size(&mut ctx, size));
+        slider.draw(&mut ctx, rect, WidgetEvent::default());
+
+        // handle travels 40 pixels, ticks are at 0, 25, 50, 75 and 100%
+        let ticks: alloc::vec::Vec<i32> = (0..41)
+            .filter(|x| ctx.draw_target.pixel(Point::new(*x, 9)) == Some(Rgb888::RED))
+            .collect();
+        assert_eq!(ticks, [0, 10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn value_bubble_is_shown_only_while_dragging() {
+        let mut ctx = framebuffer_context(Size::new(40, 20));
+        let mut slider = Slider::new(0.0, Box::new(|_| {})).value_bubble(&FONT_4X6);
+        // at the top edge, the bubble cannot go above the slider
+        let rect = Rectangle::new(Point::zero(), Size::new(40, 10));
+        slider.size(&mut ctx, rect.size);
+
+        slider.draw(&mut ctx, rect, WidgetEvent::default());
+        assert!(ctx.overlays.is_empty());
+
+        let position = Point::new(10, 4);
+        let event_args = WidgetEvent {
+            system_event: &SystemEvent::Drag(position),
+            is_focused: true,
+            id: 1,
+            event: &Event::Drag(position),
+        };
+        slider.draw(&mut ctx, rect, event_args);
+        assert!(!ctx.overlays[0].2);
+        ctx.draw_overlays(&SystemEvent::Idle, false);
+
+        // "25%" is 12 pixels wide, the bubble is centered on the handle (at 1 + 38 * 0.25)
+        let label_color = ctx.theme.label_color;
+        let dump = ctx
+            .draw_target
+            .ascii(|color| if color == label_color { '#' } else { '.' });
+        let rows: alloc::vec::Vec<&str> = dump.lines().take(8).map(|row| &row[..20]).collect();
+        assert_eq!(
+            rows,
+            [
+                "....................",
+                "....................",
+                ".....#..###.#.......",
+                "....#.#.#.....#.....",
+                "......#.##...#......",
+                ".....#....#.#.......",
+                "....###.##....#.....",
+                "....................",
+            ]
+        );
+    }
+
+    #[test]
+    fn held_increase_accelerates() {
+        let hold = HoldAcceleration::new();
+        let steps: alloc::vec::Vec<f32> =
+            (0..30).map(|frame| hold.step(frame, 0.01, true)).collect();
+
+        assert!(steps[29] > steps[0]);
+        // releasing resets the acceleration
+        assert_eq!(hold.step(40, 0.01, true), 0.01);
+    }
+
+    #[test]
+    fn held_increase_accelerates_across_frames() {
+        let hold = HoldAcceleration::new();
+        let value = Cell::new(0.0);
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.draw_target.set_allow_out_of_bounds_drawing(true);
+        let build = || {
+            let mut ui = LinearLayoutBuilder::default();
+            ui.add_widget(
+                Slider::new(value.get(), Box::new(|new_value| value.set(new_value)))
+                    .accelerate(&hold),
+            );
+            ui.finish()
+        };
+
+        ctx.update(build());
+        ctx.focus_first();
+        let mut steps = alloc::vec::Vec::new();
+        for _ in 0..30 {
+            let previous = value.get();
+            ctx.simulate(build(), SystemEvent::Increase(0.01));
+            steps.push(value.get() - previous);
+        }
+
+        assert!((steps[0] - 0.01).abs() < 1e-6);
+        assert!(steps[29] > steps[0]);
+    }
 }
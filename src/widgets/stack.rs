@@ -0,0 +1,122 @@
+use alloc::{boxed::Box, vec::Vec};
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+use crate::{EventResult, SystemEvent, UiContext};
+
+use super::{StackedTarget, UiBuilder, Widget, WidgetEvent, WidgetObject};
+
+/// Stacks its children in the same rect, later children are drawn on top of earlier ones (e.g. a
+/// badge over an icon). Like [super::root_layout::RootLayout], a pointer event goes only to the
+/// topmost child under the pointer
+pub struct Stack<'a, D, C>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    children: Vec<WidgetObject<'a, D, C>>,
+}
+
+impl<'a, D, C> Stack<'a, D, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+        }
+    }
+}
+
+impl<'a, D, C> Default for Stack<'a, D, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, D, C> UiBuilder<'a, D, C> for Stack<'a, D, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn add_widget_obj(&mut self, widget: WidgetObject<'a, D, C>) {
+        self.children.push(widget);
+    }
+
+    fn finish(self) -> WidgetObject<'a, D, C> {
+        WidgetObject::new(Box::new(self))
+    }
+}
+
+impl<'a, D, C> Widget<'a, D, C> for Stack<'a, D, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn size(&mut self, context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
+        self.children.iter_mut().fold(Size::zero(), |size, child| {
+            size.component_max(child.size(context, hint))
+        })
+    }
+
+    fn layout(&mut self, context: &mut UiContext<'a, D, C>, rect: Rectangle) {
+        for child in self.children.iter_mut() {
+            child.layout(context, rect);
+        }
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        _rect: Rectangle,
+        event_args: WidgetEvent,
+    ) -> EventResult {
+        let mut event_result = EventResult::Pass;
+        let target = StackedTarget::new(
+            self.children.iter().map(|child| child.computed_rect),
+            event_args.system_event,
+        );
+
+        for (index, child) in self.children.iter_mut().enumerate() {
+            let event = if target.includes(index) {
+                event_args.system_event
+            } else {
+                &SystemEvent::Idle
+            };
+            child.draw_sibling(context, event, &mut event_result);
+        }
+
+        event_result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{framebuffer_context, Block, Fixed};
+    use core::cell::Cell;
+    use embedded_graphics::pixelcolor::Rgb888;
+
+    #[test]
+    fn last_child_draws_on_top() {
+        let size = Size::new(16, 16);
+        let placed = Cell::new(Rectangle::zero());
+        let mut ctx = framebuffer_context(size);
+
+        let mut ui = Stack::new();
+        ui.overlay(|ui| {
+            ui.add_widget(Block(Size::new(8, 8), Rgb888::RED));
+            ui.add_widget(Fixed(Size::new(8, 8), &placed));
+            ui.add_widget(Block(Size::new(8, 8), Rgb888::GREEN));
+        });
+        ctx.update(ui.finish());
+
+        let bounds = Rectangle::new(Point::zero(), size);
+        assert_eq!(placed.get(), bounds);
+        assert!(ctx.draw_target.is_filled(bounds, Rgb888::GREEN));
+    }
+}
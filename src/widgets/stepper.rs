@@ -0,0 +1,143 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Circle, Line, PrimitiveStyle, Rectangle},
+};
+
+use super::{Widget, WidgetEvent};
+use crate::{EventResult, UiContext};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepState {
+    Completed,
+    Active,
+    Pending,
+}
+
+/// Step indicator for multi-step flows. Draws the steps as dots connected by a line: completed
+/// steps are filled with the accent color, the current step with the foreground color and
+/// pending steps with the background color of the progress style
+pub struct Stepper {
+    pub steps: usize,
+    /// Zero-based index of the current step
+    pub current: usize,
+    /// Diameter of the step dot
+    pub dot_size: u32,
+}
+
+impl Stepper {
+    pub fn new(steps: usize, current: usize) -> Self {
+        Self {
+            steps,
+            current,
+            dot_size: 8,
+        }
+    }
+
+    pub fn step_state(&self, step: usize) -> StepState {
+        match step.cmp(&self.current) {
+            core::cmp::Ordering::Less => StepState::Completed,
+            core::cmp::Ordering::Equal => StepState::Active,
+            core::cmp::Ordering::Greater => StepState::Pending,
+        }
+    }
+
+    /// Returns the bounding box of every step dot, spread evenly across the rect
+    fn step_dots(&self, rect: Rectangle) -> impl Iterator<Item = Circle> {
+        let span = rect.size.width.saturating_sub(self.dot_size) as i32;
+        let gaps = self.steps.saturating_sub(1).max(1) as i32;
+        let y = rect.top_left.y + (rect.size.height.saturating_sub(self.dot_size) / 2) as i32;
+        let dot_size = self.dot_size;
+
+        (0..self.steps as i32).map(move |step| {
+            Circle::new(
+                Point::new(rect.top_left.x + span * step / gaps, y),
+                dot_size,
+            )
+        })
+    }
+}
+
+impl<'a, D, C> Widget<'a, D, C> for Stepper
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor + 'a,
+{
+    fn size(&mut self, _context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
+        Size::new(hint.width, self.dot_size.min(hint.height))
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        _event_args: WidgetEvent,
+    ) -> EventResult {
+        if self.steps == 0 {
+            return EventResult::Pass;
+        }
+
+        let style = context.theme.progress_style;
+        let completed_color = style
+            .accent_color
+            .expect("Stepper must have a accent color for a drawing");
+        let active_color = style.foreground_color.unwrap_or(completed_color);
+        let pending_color = style.background_color.unwrap_or(active_color);
+
+        let mut dots = self.step_dots(rect);
+        if let (Some(first), Some(last)) = (dots.next(), dots.last()) {
+            let _ = Line::new(first.center(), last.center())
+                .into_styled(PrimitiveStyle::with_stroke(pending_color, 1))
+                .draw(&mut context.draw_target);
+        }
+
+        for (step, dot) in self.step_dots(rect).enumerate() {
+            let color = match self.step_state(step) {
+                StepState::Completed => completed_color,
+                StepState::Active => active_color,
+                StepState::Pending => pending_color,
+            };
+
+            let _ = dot
+                .into_styled(PrimitiveStyle::with_fill(color))
+                .draw(&mut context.draw_target);
+        }
+
+        EventResult::Pass
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mock_context;
+    use embedded_graphics::pixelcolor::Rgb888;
+
+    #[test]
+    fn steps_before_current_are_completed() {
+        let mut stepper = Stepper::new(5, 2);
+        let states: alloc::vec::Vec<StepState> =
+            (0..5).map(|step| stepper.step_state(step)).collect();
+
+        assert_eq!(
+            states,
+            [
+                StepState::Completed,
+                StepState::Completed,
+                StepState::Active,
+                StepState::Pending,
+                StepState::Pending,
+            ]
+        );
+
+        let mut ctx = mock_context::<Rgb888>();
+        ctx.theme.progress_style.accent_color = Some(Rgb888::GREEN);
+        let rect = Rectangle::new(Point::zero(), Size::new(64, 8));
+        stepper.draw(&mut ctx, rect, WidgetEvent::default());
+
+        let dots: alloc::vec::Vec<Circle> = stepper.step_dots(rect).collect();
+        for (step, dot) in dots.into_iter().enumerate() {
+            let completed = ctx.draw_target.get_pixel(dot.center()) == Some(Rgb888::GREEN);
+            assert_eq!(completed, step < 2);
+        }
+    }
+}
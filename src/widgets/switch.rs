@@ -0,0 +1,202 @@
+use alloc::boxed::Box;
+use core::cell::Cell;
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Circle, PrimitiveStyle, Rectangle, RoundedRectangle},
+};
+
+use super::{Widget, WidgetEvent};
+use crate::{easing, Event, EventResult, UiContext};
+
+/// Knob travel time in frames
+const KNOB_FRAMES: u32 = 4;
+
+/// Persistent state of the [Switch], keep it outside of the UI tree so the knob animation
+/// survives between frames. The switch borrows it shared, so the same state can be passed to the
+/// switch rebuilt every frame
+#[derive(Clone, Debug, Default)]
+pub struct SwitchState {
+    on: Cell<bool>,
+    changed_frame: Cell<Option<u32>>,
+}
+
+impl SwitchState {
+    pub fn new(on: bool) -> Self {
+        Self {
+            on: Cell::new(on),
+            changed_frame: Cell::new(None),
+        }
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.on.get()
+    }
+
+    /// Flips the state, the knob starts moving at `frame`
+    pub fn toggle(&self, frame: u32) {
+        self.on.set(!self.on.get());
+        self.changed_frame.set(Some(frame));
+    }
+
+    /// Returns the knob position at `frame`, 0.0 is off and 1.0 is on
+    pub fn knob_position(&self, frame: u32) -> f32 {
+        let target = if self.is_on() { 1.0 } else { 0.0 };
+        let Some(changed_frame) = self.changed_frame.get() else {
+            return target;
+        };
+
+        let progress = frame.wrapping_sub(changed_frame) as f32 / KNOB_FRAMES as f32;
+        easing::interpolate(1.0 - target, target, progress, easing::ease_in_out)
+    }
+}
+
+/// Sliding on/off switch with a knob
+pub struct Switch<'a> {
+    state: &'a SwitchState,
+    callback: Box<dyn FnMut(bool) + 'a>,
+}
+
+impl<'a> Switch<'a> {
+    pub fn new(state: &'a SwitchState, callback: Box<dyn FnMut(bool) + 'a>) -> Self {
+        Self { state, callback }
+    }
+
+    fn knob_rect(&self, rect: Rectangle, frame: u32) -> Rectangle {
+        let knob_size = rect.size.height.saturating_sub(4);
+        let travel = rect.size.width.saturating_sub(knob_size + 4);
+        let offset = (travel as f32 * self.state.knob_position(frame) + 0.5) as i32;
+
+        Rectangle::new(
+            rect.top_left + Point::new(2 + offset, 2),
+            Size::new(knob_size, knob_size),
+        )
+    }
+}
+
+impl<'a, D, C> Widget<'a, D, C> for Switch<'a>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor + 'a,
+{
+    fn size(&mut self, _context: &mut UiContext<'a, D, C>, _hint: Size) -> Size {
+        Size::new(24, 12)
+    }
+
+    fn min_size(&mut self) -> Size {
+        Size::new(24, 12)
+    }
+
+    fn is_interactive(&mut self) -> bool {
+        true
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        event_args: WidgetEvent,
+    ) -> EventResult {
+        let style = context.style(&context.theme.button_style, event_args.event);
+
+        let event_result = match event_args.event {
+            Event::Focus => EventResult::Stop,
+            Event::Active(_) => {
+                context.focused_element = event_args.id;
+                self.state.toggle(context.frame_counter());
+                (self.callback)(self.state.is_on());
+                EventResult::Stop
+            }
+            _ => EventResult::Pass,
+        };
+
+        let track_color = if self.state.is_on() {
+            style.accent_color
+        } else {
+            style.background_color
+        };
+
+        let mut track_style = PrimitiveStyle::with_stroke(
+            style.stroke_color.unwrap_or(
+                style
+                    .foreground_color
+                    .expect("Switch must have a foreground color for drawing"),
+            ),
+            1,
+        );
+        track_style.fill_color = track_color;
+
+        let radius = rect.size.height / 2;
+        let _ = RoundedRectangle::with_equal_corners(rect, Size::new(radius, radius))
+            .into_styled(track_style)
+            .draw(&mut context.draw_target);
+
+        let knob = self.knob_rect(rect, context.frame_counter());
+        if let Some(knob_color) = style.foreground_color {
+            let _ = Circle::new(knob.top_left, knob.size.width)
+                .into_styled(PrimitiveStyle::with_fill(knob_color))
+                .draw(&mut context.draw_target);
+        }
+
+        event_result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::mock_context,
+        widgets::{linear_layout::LinearLayoutBuilder, UiBuilder},
+        SystemEvent,
+    };
+    use embedded_graphics::pixelcolor::Rgb888;
+
+    #[test]
+    fn activation_flips_state_and_moves_knob() {
+        let reported = Cell::new(None);
+        let state = SwitchState::new(false);
+        let mut ctx = mock_context::<Rgb888>();
+        let rect = Rectangle::new(Point::zero(), Size::new(24, 12));
+
+        ctx.frame_counter = 10;
+        let mut switch = Switch::new(&state, Box::new(|on| reported.set(Some(on))));
+        let off_knob = switch.knob_rect(rect, 10);
+        switch.draw(
+            &mut ctx,
+            rect,
+            WidgetEvent {
+                event: &Event::Active(None),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(reported.get(), Some(true));
+        assert_eq!(switch.knob_rect(rect, 10), off_knob);
+
+        let middle = switch.knob_rect(rect, 12);
+        let on_knob = switch.knob_rect(rect, 10 + KNOB_FRAMES);
+        assert!(middle.top_left.x > off_knob.top_left.x);
+        assert!(on_knob.top_left.x > middle.top_left.x);
+        assert_eq!(on_knob.top_left.x + on_knob.size.width as i32, 22);
+
+        assert!(switch.state.is_on());
+    }
+
+    #[test]
+    fn state_is_kept_across_frames() {
+        let state = SwitchState::new(false);
+        let mut ctx = mock_context::<Rgb888>();
+        let build = || {
+            let mut ui = LinearLayoutBuilder::default();
+            ui.switch(&state, |_| {});
+            ui.finish()
+        };
+
+        for expected in [true, false, true] {
+            ctx.simulate(build(), SystemEvent::Active(Point::new(4, 4)));
+            assert_eq!(state.is_on(), expected);
+        }
+        ctx.update(build());
+        assert!(state.is_on());
+    }
+}
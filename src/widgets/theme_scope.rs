@@ -0,0 +1,112 @@
+use alloc::boxed::Box;
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+use super::{Widget, WidgetEvent, WidgetObject};
+use crate::{themes::Theme, EventResult, UiContext};
+
+/// Edits the theme for a [ThemeScope] subtree
+pub type ThemeOverride<'a, C> = Box<dyn Fn(&mut Theme<C>) + 'a>;
+
+/// Theme override for a subtree, like inline CSS styles applied to every widget inside. The
+/// override edits the current theme while the child measures, lays out and draws, then the
+/// previous theme is restored
+pub struct ThemeScope<'a, D, C>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    child: WidgetObject<'a, D, C>,
+    apply: ThemeOverride<'a, C>,
+}
+
+impl<'a, D, C> ThemeScope<'a, D, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    pub fn new(child: WidgetObject<'a, D, C>, apply: ThemeOverride<'a, C>) -> Self {
+        Self { child, apply }
+    }
+
+    /// Applies the override, returns the theme to restore afterwards
+    fn enter(&self, context: &mut UiContext<'a, D, C>) -> Theme<C> {
+        let parent_theme = context.theme;
+        (self.apply)(&mut context.theme);
+        parent_theme
+    }
+}
+
+impl<'a, D, C> Widget<'a, D, C> for ThemeScope<'a, D, C>
+where
+    D: DrawTarget<Color = C> + 'a,
+    C: PixelColor + 'a,
+{
+    fn size(&mut self, context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
+        let parent_theme = self.enter(context);
+        let size = self.child.size(context, hint);
+        context.theme = parent_theme;
+        size
+    }
+
+    fn layout(&mut self, context: &mut UiContext<'a, D, C>, rect: Rectangle) {
+        let parent_theme = self.enter(context);
+        self.child.layout(context, rect);
+        context.theme = parent_theme;
+    }
+
+    fn min_size(&mut self) -> Size {
+        self.child.min_size()
+    }
+
+    fn max_size(&mut self) -> Size {
+        self.child.max_size()
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        _rect: Rectangle,
+        event_args: WidgetEvent,
+    ) -> EventResult {
+        let parent_theme = self.enter(context);
+        let event_result = self.child.draw(context, event_args.system_event);
+        context.theme = parent_theme;
+        event_result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        testing::mock_context,
+        widgets::{linear_layout::LinearLayoutBuilder, UiBuilder},
+    };
+    use embedded_graphics::{
+        mono_font::ascii::FONT_4X6, pixelcolor::Rgb888, prelude::*, text::Alignment,
+    };
+
+    #[test]
+    fn label_inside_override_uses_override_color() {
+        let mut ctx = mock_context::<Rgb888>();
+
+        let mut ui = LinearLayoutBuilder::default();
+        ui.with_style_override(
+            |theme| theme.label_color = Rgb888::RED,
+            |ui| ui.label("AB", Alignment::Left, &FONT_4X6),
+        );
+        ui.label("AB", Alignment::Left, &FONT_4X6);
+        ctx.update(ui.finish());
+
+        let red_rows: alloc::vec::Vec<i32> = ctx
+            .draw_target
+            .bounding_box()
+            .points()
+            .filter(|&point| ctx.draw_target.get_pixel(point) == Some(Rgb888::RED))
+            .map(|point| point.y)
+            .collect();
+
+        assert!(!red_rows.is_empty());
+        assert!(red_rows.iter().all(|&y| y < 6));
+        assert_ne!(ctx.theme.label_color, Rgb888::RED);
+    }
+}
@@ -72,7 +72,7 @@ where
             self.base.style = context.theme.button_style;
         }
 
-        self.base.size(&self.text)
+        self.base.size(context, &self.text)
     }
 
     fn is_interactive(&mut self) -> bool {
@@ -85,7 +85,7 @@ where
         rect: Rectangle,
         event_args: WidgetEvent,
     ) -> EventResult {
-        let style = self.base.style.style(event_args.event);
+        let style = context.style(&self.base.style, event_args.event);
 
         let event_result = match event_args.event {
             Event::Focus => EventResult::Stop,
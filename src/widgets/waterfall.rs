@@ -0,0 +1,174 @@
+use crate::{themes::palette::Palette, EventResult, UiContext};
+
+use super::{Widget, WidgetEvent};
+use alloc::{vec, vec::Vec};
+use embedded_graphics::{pixelcolor::Rgb888, prelude::*, primitives::Rectangle};
+
+/// Bounded ring of spectrum rows for the [Waterfall] widget. Lives outside of the UI tree, so
+/// push a new row every frame and pass a reference to the widget
+pub struct WaterfallBuffer {
+    data: Vec<f32>,
+    width: usize,
+    depth: usize,
+    head: usize,
+    len: usize,
+}
+
+impl WaterfallBuffer {
+    /// Creates a buffer holding `depth` rows of `width` magnitudes each
+    pub fn new(width: usize, depth: usize) -> Self {
+        let width = width.max(1);
+        let depth = depth.max(1);
+        Self {
+            data: vec![0.0; width * depth],
+            width,
+            depth,
+            head: 0,
+            len: 0,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Pushes a new row of magnitudes (0.0-1.0), dropping the oldest row if the buffer is full.
+    /// Missing samples are filled with zeros, extra samples are ignored
+    pub fn push(&mut self, row: &[f32]) {
+        self.head = (self.head + 1) % self.depth;
+        self.len = (self.len + 1).min(self.depth);
+
+        let start = self.head * self.width;
+        let target = &mut self.data[start..start + self.width];
+        for (i, value) in target.iter_mut().enumerate() {
+            *value = row.get(i).copied().unwrap_or(0.0);
+        }
+    }
+
+    /// Returns a row by age, `0` is the newest one
+    pub fn row(&self, age: usize) -> Option<&[f32]> {
+        if age >= self.len {
+            return None;
+        }
+
+        let index = (self.head + self.depth - age) % self.depth;
+        let start = index * self.width;
+        Some(&self.data[start..start + self.width])
+    }
+}
+
+/// Waterfall (scrolling spectrogram) widget. Draws the newest row at the top and older rows below,
+/// mapping magnitudes to colors with a [Palette]
+pub struct Waterfall<'a, C: PixelColor> {
+    buffer: &'a WaterfallBuffer,
+    palette: &'a Palette<C>,
+}
+
+impl<'a, C> Waterfall<'a, C>
+where
+    C: RgbColor + From<Rgb888>,
+{
+    pub fn new(buffer: &'a WaterfallBuffer, palette: &'a Palette<C>) -> Self {
+        Self { buffer, palette }
+    }
+
+    fn color_at(&self, rect: Rectangle, point: Point, background: C) -> C {
+        let Some(row) = self.buffer.row(point.y as usize) else {
+            return background;
+        };
+
+        let sample = point.x as usize * self.buffer.width() / rect.size.width as usize;
+        let value = row[sample.min(row.len() - 1)];
+
+        self.palette.color(value).unwrap_or(background)
+    }
+}
+
+impl<'a, D, C> Widget<'a, D, C> for Waterfall<'a, C>
+where
+    D: DrawTarget<Color = C>,
+    C: RgbColor + From<Rgb888> + 'a,
+{
+    fn size(&mut self, _context: &mut UiContext<'a, D, C>, hint: Size) -> Size {
+        Size::new(hint.width, hint.height.min(self.buffer.depth() as u32))
+    }
+
+    fn max_size(&mut self) -> Size {
+        Size::new(u32::MAX, self.buffer.depth() as u32)
+    }
+
+    fn draw(
+        &mut self,
+        context: &mut UiContext<'a, D, C>,
+        rect: Rectangle,
+        _event_args: WidgetEvent,
+    ) -> EventResult {
+        if rect.is_zero_sized() {
+            return EventResult::Pass;
+        }
+
+        let background = context.theme.plot_style.background_color;
+
+        let colors = rect
+            .points()
+            .map(|point| self.color_at(rect, point - rect.top_left, background));
+
+        let _ = context.draw_target.fill_contiguous(&rect, colors);
+
+        EventResult::Pass
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testing::mock_context, themes::palette::PaletteBand};
+    use embedded_graphics::mock_display::MockDisplay;
+
+    fn render(buffer: &WaterfallBuffer) -> MockDisplay<Rgb888> {
+        let palette = Palette::new()
+            .band(PaletteBand::new([0.0, 0.5], Rgb888::GREEN))
+            .band(PaletteBand::new([0.5, 1.0], Rgb888::RED));
+        let mut ctx = mock_context();
+        Waterfall::new(buffer, &palette).draw(
+            &mut ctx,
+            Rectangle::new(Point::zero(), Size::new(4, 8)),
+            WidgetEvent::default(),
+        );
+        ctx.draw_target
+    }
+
+    #[test]
+    fn push_scrolls_rows_down() {
+        let mut buffer = WaterfallBuffer::new(4, 8);
+
+        buffer.push(&[1.0; 4]);
+        let first = render(&buffer);
+        assert_eq!(first.get_pixel(Point::new(0, 0)), Some(Rgb888::RED));
+
+        buffer.push(&[0.0; 4]);
+        let second = render(&buffer);
+        assert_eq!(second.get_pixel(Point::new(0, 0)), Some(Rgb888::GREEN));
+        assert_eq!(second.get_pixel(Point::new(0, 1)), Some(Rgb888::RED));
+        assert_eq!(
+            second.get_pixel(Point::new(0, 2)),
+            first.get_pixel(Point::new(0, 1))
+        );
+    }
+
+    #[test]
+    fn buffer_drops_oldest_row() {
+        let mut buffer = WaterfallBuffer::new(1, 2);
+        buffer.push(&[0.1]);
+        buffer.push(&[0.2]);
+        buffer.push(&[0.3]);
+
+        assert_eq!(buffer.row(0), Some(&[0.3][..]));
+        assert_eq!(buffer.row(1), Some(&[0.2][..]));
+        assert_eq!(buffer.row(2), None);
+    }
+}